@@ -0,0 +1,139 @@
+//! Discovers the solutions in src/<year>/day<day>.rs and generates the module tree
+//! for them, along with the list of years and the registry of every day. The
+//! generated code is included by main.rs
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Solutions and helper modules of one year
+struct Year {
+    year: u32,
+    days: Vec<(usize, PathBuf)>,
+    helpers: Vec<(String, PathBuf)>,
+}
+
+fn scan_year(year: u32, dir: &Path) -> io::Result<Year> {
+    let mut days = Vec::new();
+    let mut helpers = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        let Some(stem) = name.strip_suffix(".rs") else {
+            continue;
+        };
+
+        match stem
+            .strip_prefix("day")
+            .and_then(|n| n.parse::<usize>().ok())
+        {
+            Some(day) => days.push((day, path)),
+            // mod.rs files from before discovery are ignored, the module is generated
+            None if stem != "mod" => helpers.push((stem.to_owned(), path)),
+            None => (),
+        }
+    }
+
+    days.sort();
+    helpers.sort();
+
+    Ok(Year {
+        year,
+        days,
+        helpers,
+    })
+}
+
+fn scan(src: &Path) -> io::Result<Vec<Year>> {
+    let mut years = Vec::new();
+
+    for entry in fs::read_dir(src)? {
+        let path = entry?.path();
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+
+        if !path.is_dir() || name.len() != 4 {
+            continue;
+        }
+
+        if let Ok(year) = name.parse::<u32>() {
+            let year = scan_year(year, &path)?;
+
+            if !year.days.is_empty() {
+                years.push(year);
+            }
+        }
+    }
+
+    years.sort_by_key(|y| y.year);
+    Ok(years)
+}
+
+fn generate(years: &[Year]) -> String {
+    let mut out = String::from("// Generated by build.rs from the files in src/<year>/\n\n");
+
+    for y in years {
+        writeln!(out, "mod year{:02} {{", y.year % 100).unwrap();
+        writeln!(out, "    use aocutils::solution::Puzzle;\n").unwrap();
+
+        for (name, path) in &y.helpers {
+            writeln!(
+                out,
+                "    #[path = {:?}]\n    mod {};",
+                path.display().to_string(),
+                name
+            )
+            .unwrap();
+        }
+
+        for (day, path) in &y.days {
+            writeln!(
+                out,
+                "    #[path = {:?}]\n    mod day{};",
+                path.display().to_string(),
+                day
+            )
+            .unwrap();
+        }
+
+        writeln!(out, "\n    pub static DAYS: [Puzzle; {}] = [", y.days.len()).unwrap();
+        for (day, _) in &y.days {
+            writeln!(out, "        Puzzle::new::<day{0}::Day{0}>(),", day).unwrap();
+        }
+        writeln!(out, "    ];\n}}\n").unwrap();
+    }
+
+    let names: Vec<String> = years.iter().map(|y| format!("\"{}\"", y.year)).collect();
+    writeln!(
+        out,
+        "const YEARS: [&str; {}] = [{}];\n",
+        years.len(),
+        names.join(", ")
+    )
+    .unwrap();
+
+    let days: Vec<String> = years
+        .iter()
+        .map(|y| format!("&year{:02}::DAYS", y.year % 100))
+        .collect();
+    writeln!(out, "fn registry() -> Registry {{").unwrap();
+    writeln!(out, "    Registry::new(&[{}])", days.join(", ")).unwrap();
+    writeln!(out, "}}").unwrap();
+
+    out
+}
+
+fn main() -> io::Result<()> {
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let src = root.join("src");
+    let years = scan(&src)?;
+
+    println!("cargo::rerun-if-changed=src");
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("years.rs");
+    fs::write(out, generate(&years))
+}
@@ -1,7 +1,5 @@
-use std::fs;
-use std::io;
-
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Example, Solution};
 
 fn replace_min(maxes: &mut [u32], val: u32) {
     let (idx, min) = maxes
@@ -48,10 +46,28 @@ fn part2(input: &str) -> u32 {
     highest.iter().sum::<u32>()
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2022/day1.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u32 = 2022;
+    const DAY: usize = 1;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day1_example.txt",
+        part1: Some("24000"),
+        part2: Some("45000"),
+    }];
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
@@ -1,8 +1,7 @@
-use std::fs;
-use std::io;
 use std::str::FromStr;
 
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
 
 #[derive(Debug, PartialEq)]
 enum Instructions {
@@ -148,10 +147,23 @@ fn part2(input: &str) -> String {
     ret
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2022/day10.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day10;
 
-    Ok(())
+impl Solution for Day10 {
+    const YEAR: u32 = 2022;
+    const DAY: usize = 10;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
@@ -1,9 +1,8 @@
 use std::cell::RefCell;
 use std::collections::VecDeque;
-use std::fs;
-use std::io;
 
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
 
 type MonkeyOp = fn(u64, Option<u64>) -> u64;
 
@@ -72,29 +71,34 @@ impl Monkey {
     }
 }
 
-fn create_op(line: &str) -> (Option<u64>, MonkeyOp) {
+/// Parses the operation on @line_no, @line
+fn create_op(line_no: usize, line: &str) -> Result<(Option<u64>, MonkeyOp), AocError> {
     let parts: Vec<&str> = line.split_whitespace().skip(4).collect();
 
-    let closure = match parts[0] {
-        "*" => |n, n2| if let Some(n2) = n2 { n * n2 } else { n * n },
-        "+" => |n, n2| if let Some(n2) = n2 { n + n2 } else { n + n },
-        _ => |n, _| {
-            println!("invalid operator!");
-            n
-        },
+    let closure = match parts[..] {
+        ["*", _] => |n, n2| if let Some(n2) = n2 { n * n2 } else { n * n },
+        ["+", _] => |n, n2| if let Some(n2) = n2 { n + n2 } else { n + n },
+        _ => {
+            return Err(AocError::parse(
+                line_no,
+                1,
+                format!("invalid operation \"{}\"", line),
+            ));
+        }
     };
 
-    (parts[1].parse::<u64>().ok(), closure)
+    Ok((parts[1].parse::<u64>().ok(), closure))
 }
 
-fn parse_monkeys(input: &str) -> Vec<Monkey> {
-    let mut lines = input.lines().map(|l| l.trim());
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, AocError> {
+    let mut lines = input.lines().map(|l| l.trim()).enumerate();
     let mut monkeys = Vec::new();
 
     while let Some(_) = lines.next() {
         let items: VecDeque<u64> = lines
             .next()
             .unwrap()
+            .1
             .split_whitespace()
             .filter_map(|s| {
                 s.chars()
@@ -105,13 +109,15 @@ fn parse_monkeys(input: &str) -> Vec<Monkey> {
             })
             .collect();
 
-        let (operand, operation) = create_op(lines.next().unwrap());
+        let (i, line) = lines.next().unwrap();
+        let (operand, operation) = create_op(i + 1, line)?;
 
         let mut nums: [usize; 3] = [0; 3];
         for n in &mut nums {
             *n = lines
                 .next()
                 .unwrap()
+                .1
                 .split_whitespace()
                 .filter_map(|s| s.parse::<usize>().ok())
                 .collect::<Vec<usize>>()[0];
@@ -128,11 +134,11 @@ fn parse_monkeys(input: &str) -> Vec<Monkey> {
         lines.next();
     }
 
-    monkeys
+    Ok(monkeys)
 }
 
-fn part1(input: &str) -> usize {
-    let mut monkeys = parse_monkeys(input);
+fn part1(input: &str) -> Result<usize, AocError> {
+    let mut monkeys = parse_monkeys(input)?;
 
     for _ in 0..20 {
         for m in &monkeys {
@@ -142,11 +148,12 @@ fn part1(input: &str) -> usize {
     }
 
     monkeys.sort_by_key(|m| m.get_num_inspected());
-    monkeys[monkeys.len() - 1].get_num_inspected() * monkeys[monkeys.len() - 2].get_num_inspected()
+    Ok(monkeys[monkeys.len() - 1].get_num_inspected()
+        * monkeys[monkeys.len() - 2].get_num_inspected())
 }
 
-fn part2(input: &str) -> usize {
-    let mut monkeys = parse_monkeys(input);
+fn part2(input: &str) -> Result<usize, AocError> {
+    let mut monkeys = parse_monkeys(input)?;
 
     let divisor = monkeys.iter().map(|m| m.test).product();
 
@@ -162,13 +169,27 @@ fn part2(input: &str) -> usize {
     }
 
     monkeys.sort_by_key(|m| m.get_num_inspected());
-    monkeys[monkeys.len() - 1].get_num_inspected() * monkeys[monkeys.len() - 2].get_num_inspected()
+    Ok(monkeys[monkeys.len() - 1].get_num_inspected()
+        * monkeys[monkeys.len() - 2].get_num_inspected())
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2022/day11.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u32 = 2022;
+    const DAY: usize = 11;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input)?.into())
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input)?.into())
+    }
 }
@@ -1,93 +1,75 @@
-use std::fs;
-use std::io;
-
 use aocutils::coord::Coord;
+use aocutils::error::AocError;
+use aocutils::grid::Grid;
 use aocutils::grid::algo::*;
-use aocutils::timeln;
-
-fn cost_fn(grid: &[Vec<u8>], u: Coord, _cost: usize, v: Coord) -> usize {
-    let (ux, uy) = u.as_unsigned().unwrap();
-    let (vx, vy) = v.as_unsigned().unwrap();
+use aocutils::solution::{Answer, Example, Solution};
 
-    if grid[ux][uy] == grid[vx][vy]
-        || grid[ux][uy] + 1 == grid[vx][vy]
-        || grid[ux][uy] > grid[vx][vy]
-    {
-        1
-    } else {
-        usize::MAX
-    }
+pub struct Heightmap {
+    grid: Grid<u8>,
+    start: Coord,
+    end: Coord,
 }
 
-fn part1(input: &str) -> usize {
-    let mut cells = Vec::new();
-    let (mut start, mut end) = (Coord::new(0, 0), Coord::new(0, 0));
-    for (i, line) in input.lines().map(|l| l.as_bytes()).enumerate() {
-        cells.push(Vec::new());
-
-        for (j, b) in line.iter().enumerate() {
-            match *b {
-                b'a'..=b'z' => cells[i].push(*b),
-                b'S' => {
-                    start = Coord::new(i as i64, j as i64);
-                    cells[i].push(b'a');
-                }
-                b'E' => {
-                    end = Coord::new(i as i64, j as i64);
-                    cells[i].push(b'z');
-                }
-                _ => panic!(),
-            }
-        }
-    }
+/// Parses the heightmap, replacing S and E with their heights
+fn heightmap(input: &str) -> Result<Heightmap, AocError> {
+    let mut grid = Grid::try_parse(input, |c| {
+        matches!(c, 'a'..='z' | 'S' | 'E').then_some(c as u8)
+    })?;
+    let start = grid
+        .find(&b'S')
+        .ok_or_else(|| AocError::unsolvable("there's no start (S)"))?;
+    let end = grid
+        .find(&b'E')
+        .ok_or_else(|| AocError::unsolvable("there's no best signal (E)"))?;
 
-    let path = djikstra(&cells, start, end, cost_fn).unwrap();
+    grid[start] = b'a';
+    grid[end] = b'z';
 
-    path.len()
+    Ok(Heightmap { grid, start, end })
 }
 
-fn part2(input: &str) -> usize {
-    let mut cells = Vec::new();
-    let mut end = Coord::new(0, 0);
-    let mut starts = Vec::new();
+fn climbable(from: &u8, to: &u8) -> bool {
+    *to <= from + 1
+}
 
-    for (i, line) in input.lines().map(|l| l.as_bytes()).enumerate() {
-        cells.push(Vec::new());
+fn part1(map: &Heightmap) -> Result<usize, AocError> {
+    bfs_distances(&map.grid, map.start, climbable)[map.end]
+        .ok_or_else(|| AocError::unsolvable("the best signal can't be reached from the start"))
+}
 
-        for (j, b) in line.iter().enumerate() {
-            match *b {
-                b'b'..=b'z' => cells[i].push(*b),
-                b'a' | b'S' => {
-                    starts.push(Coord::new(i as i64, j as i64));
-                    cells[i].push(b'a');
-                }
-                b'E' => {
-                    end = Coord::new(i as i64, j as i64);
-                    cells[i].push(b'z');
-                }
-                _ => panic!(),
-            }
-        }
-    }
+fn part2(map: &Heightmap) -> Result<usize, AocError> {
+    // walk down from the end, so that one search finds the distance to every a
+    let dist = bfs_distances(&map.grid, map.end, |from, to| climbable(to, from));
 
-    starts
-        .iter()
-        .filter_map(|s| {
-            let len = djikstra(&cells, *s, end, cost_fn).unwrap().len();
-            if len != 0 {
-                Some(len)
-            } else {
-                None
-            }
-        })
+    map.grid
+        .positions(&b'a')
+        .filter_map(|c| dist[c])
         .min()
-        .unwrap_or(0)
+        .ok_or_else(|| AocError::unsolvable("the best signal can't be reached from any a"))
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2022/day12.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day12;
 
-    Ok(())
+impl Solution for Day12 {
+    const YEAR: u32 = 2022;
+    const DAY: usize = 12;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day12_example.txt",
+        part1: Some("31"),
+        part2: Some("29"),
+    }];
+
+    type Input<'a> = Heightmap;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        heightmap(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input)?.into())
+    }
 }
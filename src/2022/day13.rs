@@ -1,9 +1,8 @@
 use std::collections::VecDeque;
 use std::fmt::Display;
-use std::fs;
-use std::io;
 
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
 
 #[derive(Debug, Clone, Eq)]
 enum ListItem {
@@ -162,10 +161,23 @@ fn part2(input: &str) -> usize {
         .product::<usize>()
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2022/day13.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day13;
 
-    Ok(())
+impl Solution for Day13 {
+    const YEAR: u32 = 2022;
+    const DAY: usize = 13;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
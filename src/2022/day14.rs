@@ -1,9 +1,7 @@
-use std::fs;
-use std::io;
-
 use aocutils::coord::*;
+use aocutils::error::AocError;
 use aocutils::grid::{direction::*, in_bounds};
-use aocutils::timeln;
+use aocutils::solution::{Answer, Solution};
 
 const EXTRA_COLS: i64 = 200;
 
@@ -189,10 +187,23 @@ fn part2(input: &str) -> usize {
         .sum::<usize>()
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2022/day14.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u32 = 2022;
+    const DAY: usize = 14;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
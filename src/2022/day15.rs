@@ -1,9 +1,8 @@
 use std::collections::HashMap;
-use std::fs;
-use std::io;
 
 use aocutils::coord::*;
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
 
 struct Sensor {
     coord: Coord,
@@ -167,13 +166,23 @@ fn part2(input: &str, low: Coord, high: Coord) -> i64 {
     x * 4_000_000 + y
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2022/day15.txt")?;
-    timeln!("part1: {}", part1(&input, 2_000_000));
-    timeln!(
-        "part2: {}",
-        part2(&input, Coord::new(0, 0), Coord::new(4_000_000, 4_000_000))
-    );
+pub struct Day15;
 
-    Ok(())
+impl Solution for Day15 {
+    const YEAR: u32 = 2022;
+    const DAY: usize = 15;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input, 2_000_000).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input, Coord::new(0, 0), Coord::new(4_000_000, 4_000_000)).into())
+    }
 }
@@ -1,9 +1,8 @@
 use std::collections::VecDeque;
-use std::fs;
-use std::io;
 
+use aocutils::error::AocError;
 use aocutils::graph::*;
-use aocutils::timeln;
+use aocutils::solution::{Answer, Solution};
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 struct ValveState {
@@ -211,10 +210,23 @@ fn part2(input: &str) -> u64 {
     max
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2022/day16.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day16;
 
-    Ok(())
+impl Solution for Day16 {
+    const YEAR: u32 = 2022;
+    const DAY: usize = 16;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
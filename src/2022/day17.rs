@@ -1,10 +1,9 @@
-use std::fs;
-use std::io;
 use std::collections::VecDeque;
 
 use aocutils::coord::*;
 use aocutils::direction::*;
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
 
 #[derive(Debug)]
 struct Space {
@@ -213,9 +212,6 @@ fn part2(input: &str) -> usize {
     let mut direction_iter = input.trim().chars().cycle().map(char_to_dir);
 
     while n_rocks < 1_000_000_000_000 {
-        if n_rocks.is_multiple_of(1_000_000) {
-            println!("{}", n_rocks);
-        }
         let rt = rocks_iter.next().unwrap();
         let mut r = Rock::new(*rt);
         r.translate(Coord::new(2, (hp + 4) as i64));
@@ -234,10 +230,23 @@ fn part2(input: &str) -> usize {
     space.get_highest()
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2022/day17.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u32 = 2022;
+    const DAY: usize = 17;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
@@ -1,7 +1,5 @@
-use std::fs;
-use std::io;
-
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Example, Solution};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum Shapes {
@@ -123,10 +121,28 @@ fn part2(input: &str) -> u64 {
     rounds.iter().map(|r| r.score).sum::<u64>()
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2022/day2.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u32 = 2022;
+    const DAY: usize = 2;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day2_example.txt",
+        part1: Some("15"),
+        part2: Some("12"),
+    }];
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
@@ -1,8 +1,7 @@
 use std::collections::HashSet;
-use std::fs;
-use std::io;
 
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Example, Solution};
 
 fn part1(input: &str) -> u64 {
     let mut sum: u64 = 0;
@@ -50,10 +49,28 @@ fn part2(input: &str) -> u64 {
     sum
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2022/day3.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day3;
 
-    Ok(())
+impl Solution for Day3 {
+    const YEAR: u32 = 2022;
+    const DAY: usize = 3;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day3_example.txt",
+        part1: Some("157"),
+        part2: Some("70"),
+    }];
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
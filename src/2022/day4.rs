@@ -1,7 +1,7 @@
-use std::fs;
-use std::{io, ops::RangeInclusive};
+use std::ops::RangeInclusive;
 
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Example, Solution};
 
 trait Within<T: PartialOrd> {
     fn within(&self, other: &RangeInclusive<T>) -> bool;
@@ -66,10 +66,28 @@ fn part2(input: &str) -> usize {
         .count()
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2022/day4.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day4;
 
-    Ok(())
+impl Solution for Day4 {
+    const YEAR: u32 = 2022;
+    const DAY: usize = 4;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day4_example.txt",
+        part1: Some("2"),
+        part2: Some("4"),
+    }];
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
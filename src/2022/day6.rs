@@ -1,9 +1,9 @@
 use std::collections::{HashSet, VecDeque};
-use std::fs;
 use std::io;
 use std::io::Error;
 
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Example, Solution};
 
 fn part1(input: &str) -> io::Result<usize> {
     let mut last_chars: VecDeque<char> = VecDeque::new();
@@ -41,10 +41,28 @@ fn part2(input: &str) -> io::Result<usize> {
     Err(Error::new(io::ErrorKind::InvalidData, ""))
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2022/day6.txt")?;
-    timeln!("part1: {}", part1(&input)?);
-    timeln!("part2: {}", part2(&input)?);
+pub struct Day6;
 
-    Ok(())
+impl Solution for Day6 {
+    const YEAR: u32 = 2022;
+    const DAY: usize = 6;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day6_example.txt",
+        part1: Some("7"),
+        part2: Some("19"),
+    }];
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input)?.into())
+    }
 }
@@ -1,22 +1,17 @@
 use std::cell::RefCell;
-use std::fs;
-use std::io;
 use std::rc::Rc;
 use std::rc::Weak;
 
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
 
 struct File {
-    name: String,
     size: usize,
 }
 
 impl File {
-    fn new(name: &str, size: usize) -> Self {
-        File {
-            name: String::from(name),
-            size,
-        }
+    fn new(size: usize) -> Self {
+        File { size }
     }
 }
 
@@ -60,19 +55,6 @@ impl Dir {
 
         self.size = self.files.iter().map(|f| f.size).sum::<usize>() + sdsize;
     }
-
-    #[allow(unused)]
-    fn print(&self, level: usize) {
-        println!("{}{} {}", " ".repeat(level * 4), self.name, self.size);
-
-        for d in &self.subdirs {
-            d.borrow().print(level + 1);
-        }
-
-        for f in &self.files {
-            println!("{}{} {}", " ".repeat((level + 1) * 4), f.name, f.size);
-        }
-    }
 }
 
 fn cd(cwd: Rc<RefCell<Dir>>, dir: &str) -> Rc<RefCell<Dir>> {
@@ -107,7 +89,7 @@ fn parse_input(root: &Rc<RefCell<Dir>>, input: &str) {
                 }
                 _ => cwd
                     .borrow_mut()
-                    .add_file(File::new(parts[1], parts[0].parse::<usize>().unwrap())),
+                    .add_file(File::new(parts[0].parse::<usize>().unwrap())),
             }
         }
     }
@@ -166,10 +148,23 @@ fn part2(input: &str) -> usize {
     part2_helper(&root, size_needed)
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2022/day7.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u32 = 2022;
+    const DAY: usize = 7;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
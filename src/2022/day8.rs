@@ -1,9 +1,7 @@
-use std::fs;
-use std::io;
-
 use aocutils::coord::Coord;
+use aocutils::error::AocError;
 use aocutils::grid::direction::GridDirection;
-use aocutils::timeln;
+use aocutils::solution::{Answer, Solution};
 
 #[derive(Debug)]
 struct Tree {
@@ -151,10 +149,23 @@ fn part2(input: &str) -> usize {
         .unwrap()
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2022/day8.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u32 = 2022;
+    const DAY: usize = 8;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
@@ -1,10 +1,8 @@
-use std::fs;
-use std::io;
-
 use aocutils::coord::Coord;
 use aocutils::direction::*;
+use aocutils::error::AocError;
 use aocutils::grid::direction::GridDirection;
-use aocutils::timeln;
+use aocutils::solution::{Answer, Solution};
 
 fn move_head(mut hdx: (i64, i64), dir: Direction) -> (i64, i64) {
     use Direction::*;
@@ -121,10 +119,23 @@ fn part2(input: &str) -> usize {
     seen.len()
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2022/day9.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u32 = 2022;
+    const DAY: usize = 9;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
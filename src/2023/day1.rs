@@ -1,10 +1,26 @@
-use std::{fs, io};
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Example, Solution};
 
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day1.txt")?;
+pub struct Day1;
 
-    println!("solution: {}", solution(&input));
-    Ok(())
+impl Solution for Day1 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 1;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day1_example.txt",
+        part1: None,
+        part2: Some("281"),
+    }];
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input).into())
+    }
 }
 
 fn check_line(line: &str) -> String {
@@ -1,13 +1,22 @@
-use std::fs;
-use std::io;
-
 use crate::year23::pipe::*;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 10;
+
+    type Input<'a> = &'a str;
 
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day10.txt")?;
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
 
-    println!("solution: {}", solution(&input));
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input).into())
+    }
 }
 
 fn solution(input: &str) -> usize {
@@ -15,7 +24,6 @@ fn solution(input: &str) -> usize {
     let mut pipes: Vec<Vec<Pipe>> = Vec::new();
     let mut start_pos: (usize, usize) = (0, 0);
 
-    println!("Making pipes...");
     for (i, line) in lines.enumerate() {
         pipes.push(Vec::new());
 
@@ -42,11 +50,9 @@ fn solution(input: &str) -> usize {
         }
     }
 
-    println!("Generating starting pipe...");
     let (r, c) = start_pos;
     pipes[r][c] = find_start_kind(start_pos, &pipes);
 
-    println!("Finding connections...");
     for i in 0..pipes.len() {
         for j in 0..pipes[0].len() {
             let pc = pipes.clone();
@@ -56,29 +62,20 @@ fn solution(input: &str) -> usize {
         }
     }
 
-    println!("Traversing loop...");
     traverse_loop(start_pos, start_pos, 0, &mut pipes);
 
     let mut n = 0;
-    for (i, r) in pipes.iter().enumerate() {
-        print!("{:4}: ", i);
-
+    for r in &pipes {
         let mut inside = false;
         for p in r {
             if p.is_marked() {
-                print!("{}", p);
                 if p.is_intersection() {
                     inside = !inside;
                 }
             } else if inside {
                 n += 1;
-                print!("O");
-            } else {
-                print!(" ");
             }
         }
-
-        println!();
     }
 
     n
@@ -1,7 +1,8 @@
 use std::collections::HashSet;
 use std::fmt::Display;
-use std::fs;
-use std::io;
+
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
 
 const EXPANSION_CONST: usize = 1_000_000;
 
@@ -35,11 +36,21 @@ impl Display for Galaxy {
     }
 }
 
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day11.txt")?;
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 11;
+
+    type Input<'a> = &'a str;
 
-    println!("solution: {}", solution(&input));
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input).into())
+    }
 }
 
 fn solution(input: &str) -> u64 {
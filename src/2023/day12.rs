@@ -1,11 +1,21 @@
-use std::fs;
-use std::io;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
 
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day12.txt")?;
-    println!("solution: {}", solution(&input));
+pub struct Day12;
 
-    Ok(())
+impl Solution for Day12 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 12;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input).into())
+    }
 }
 
 /*
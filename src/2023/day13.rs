@@ -1,12 +1,21 @@
-use std::fs;
-use std::io;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
 
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day13.txt")?;
+pub struct Day13;
 
-    println!("solution: {}", solution(&input));
+impl Solution for Day13 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 13;
 
-    Ok(())
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input).into())
+    }
 }
 
 fn find_horizontal_solution(rows: &[String]) -> u64 {
@@ -39,13 +48,10 @@ fn find_horizontal_solution(rows: &[String]) -> u64 {
                 .collect();
 
             match diff.len() {
+                1 if !smudge_found => smudge_found = true,
                 1 => {
-                    if !smudge_found {
-                        smudge_found = true;
-                    } else {
-                        found = false;
-                        break;
-                    }
+                    found = false;
+                    break;
                 }
                 0 => continue,
                 _ => {
@@ -1,38 +1,29 @@
-use std::fs;
-use std::{cmp::Ordering, io};
+use std::cmp::Ordering;
+
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Example, Solution};
 
 const NUM_CYCLES: usize = 1_000_000_000;
 
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day14.txt")?;
+pub struct Day14;
 
-    println!("solution: {}", solution(&input));
+impl Solution for Day14 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 14;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day14_example.txt",
+        part1: None,
+        part2: Some("64"),
+    }];
 
-    Ok(())
-}
+    type Input<'a> = &'a str;
 
-#[allow(unused)]
-// for debug
-fn print_cycle(cycled: &[Vec<u64>]) {
-    print!("   ");
-    for i in 0..cycled[0].len() {
-        print!("{:3}", i);
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
     }
-    println!();
-
-    for (i, r) in cycled.iter().enumerate() {
-        print!("{:3}: ", cycled.len() - i);
-        for c in r {
-            let ch = match c {
-                2 => '#',
-                1 => 'O',
-                _ => '.',
-            };
-
-            print!("{:3}", ch);
-        }
 
-        println!();
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input).into())
     }
 }
 
@@ -1,13 +1,28 @@
 use std::collections::HashMap;
-use std::fs;
-use std::io;
 
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day15.txt")?;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Example, Solution};
 
-    println!("solution: {}", solution(&input));
+pub struct Day15;
 
-    Ok(())
+impl Solution for Day15 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 15;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day15_example.txt",
+        part1: None,
+        part2: Some("145"),
+    }];
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input).into())
+    }
 }
 
 fn hash(input: &str) -> u64 {
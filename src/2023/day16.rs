@@ -1,7 +1,8 @@
-use std::fs;
-use std::io;
 use std::thread;
 
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Example, Solution};
+
 #[derive(Clone, Copy)]
 struct Tile {
     energized: bool,
@@ -151,42 +152,26 @@ impl LightBeam {
     }
 }
 
-// for debug
-#[allow(unused)]
-fn print_tiles(tiles: &Vec<Vec<Tile>>) {
-    for r in tiles {
-        for t in r {
-            match t.obstacle {
-                Some(ch) => print!("{}", ch),
-                None => {
-                    if t.energized {
-                        print!(
-                            "{}",
-                            match t.direction {
-                                (-1, 0) => '^',
-                                (1, 0) => 'V',
-                                (0, -1) => '<',
-                                (0, 1) => '>',
-                                _ => '.',
-                            }
-                        );
-                    } else {
-                        print!(".");
-                    }
-                }
-            }
-        }
+pub struct Day16;
 
-        println!();
-    }
-}
+impl Solution for Day16 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 16;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day16_example.txt",
+        part1: None,
+        part2: Some("51"),
+    }];
 
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day16.txt")?;
+    type Input<'a> = &'a str;
 
-    println!("solution: {}", solution(&input));
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input).into())
+    }
 }
 
 fn calculate_beam(beam: LightBeam, mut tiles: Vec<Vec<Tile>>) -> u64 {
@@ -1,6 +1,7 @@
 use iter_tools::*;
-use std::fs;
-use std::io;
+
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
 
 enum Direction {
     Up,
@@ -32,12 +33,21 @@ impl Point {
     }
 }
 
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day18.txt")?;
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 18;
 
-    println!("solution: {}", solution(&input));
+    type Input<'a> = &'a str;
 
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input).into())
+    }
 }
 
 fn get_area(points: &[Point]) -> u64 {
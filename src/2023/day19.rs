@@ -1,7 +1,8 @@
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::fs;
-use std::io;
+
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Rule {
@@ -57,12 +58,21 @@ impl Workflow {
     }
 }
 
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day19.txt")?;
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 19;
 
-    println!("solution: {}", solution(&input));
+    type Input<'a> = &'a str;
 
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input).into())
+    }
 }
 
 fn ch_to_idx(ch: char) -> usize {
@@ -1,11 +1,28 @@
 use std::collections::HashMap;
-use std::{fs, io};
 
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day2.txt")?;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Example, Solution};
 
-    println!("solution: {}", solution(&input));
-    Ok(())
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 2;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day2_example.txt",
+        part1: None,
+        part2: Some("2286"),
+    }];
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input).into())
+    }
 }
 
 fn parse_pull(pull: &str) -> Vec<(&str, u32)> {
@@ -1,8 +1,9 @@
 use gcd::Gcd;
 use std::collections::{HashMap, VecDeque};
-use std::fs;
 use std::hash::Hash;
-use std::io;
+
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 enum ModType {
@@ -117,12 +118,21 @@ impl Module {
     }
 }
 
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day20.txt")?;
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 20;
 
-    println!("solution: {}", solution(&input));
+    type Input<'a> = &'a str;
 
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input).into())
+    }
 }
 
 fn run_module_loop(modules: &mut [Module], iter: usize, watch: &mut HashMap<usize, usize>) {
@@ -165,8 +175,7 @@ pub fn solution(input: &str) -> u64 {
     let mut cons: Vec<(usize, Vec<&str>)> = Vec::new();
 
     let mut broadcaster_id = 0;
-    let mut id = 1;
-    for line in lines {
+    for (id, line) in (1..).zip(lines) {
         let (name, out) = line.split_once("->").unwrap();
 
         let mtype = match name.trim() {
@@ -191,7 +200,6 @@ pub fn solution(input: &str) -> u64 {
         }
 
         cons.push((id, out.split(',').map(|s| s.trim()).collect()));
-        id += 1;
     }
 
     modules.insert(0, Module::new(0, "button", ModType::Broadcast, false));
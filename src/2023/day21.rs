@@ -1,104 +1,34 @@
-use std::collections::{HashSet, VecDeque};
-use std::fs;
-use std::io;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum TileType {
-    Plot,
-    Rock,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct Tile {
-    kind: TileType,
-    path: usize,
-}
-
-impl Tile {
-    fn new(kind: TileType) -> Self {
-        Tile { kind, path: 0 }
-    }
-
-    fn is_traversable(&self) -> bool {
-        self.kind != TileType::Rock
-    }
-}
-
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day21.txt")?;
+use aocutils::coord::Coord;
+use aocutils::error::AocError;
+use aocutils::grid::Grid;
+use aocutils::grid::algo::bfs_distances;
+use aocutils::solution::{Answer, Solution};
 
-    println!("solution: {}", solution(&input, 26501365));
+pub struct Day21;
 
-    Ok(())
-}
+impl Solution for Day21 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 21;
 
-#[allow(unused)]
-fn print_grid(start: (usize, usize), step_limit: usize, grid: &[Vec<Tile>]) {
-    for (i, row) in grid.iter().enumerate() {
-        for (j, t) in row.iter().enumerate() {
-            match t.kind {
-                TileType::Plot => {
-                    if t.path == step_limit {
-                        print!("O");
-                    } else if (i, j) == start {
-                        print!("S");
-                    } else {
-                        print!(".");
-                    }
-                }
-                TileType::Rock => print!("#"),
-            }
-        }
+    type Input<'a> = Grid<char>;
 
-        println!();
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Grid::parse(input, |c| c)
     }
-}
 
-fn get_adjacent(pos: (usize, usize), grid: &[Vec<Tile>]) -> Vec<(usize, usize)> {
-    let mut adj = Vec::new();
-    let (r, c) = (pos.0, pos.1);
-
-    if r < grid.len() - 1 && grid[r + 1][c].is_traversable() {
-        adj.push((r + 1, c));
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input, 26501365)?.into())
     }
-
-    if r > 0 && grid[r - 1][c].is_traversable() {
-        adj.push((r - 1, c));
-    }
-
-    if c < grid[0].len() - 1 && grid[r][c + 1].is_traversable() {
-        adj.push((r, c + 1));
-    }
-
-    if c > 0 && grid[r][c - 1].is_traversable() {
-        adj.push((r, c - 1));
-    }
-
-    adj
 }
 
-fn bfs(start: (usize, usize), limit: usize, grid: &mut [Vec<Tile>]) {
-    let mut queue = VecDeque::new();
-    let mut seen = HashSet::new();
-
-    queue.push_back((start, 0));
-
-    while let Some((pos, path)) = queue.pop_front() {
-        grid[pos.0][pos.1].path = path;
-        if path == limit {
-            continue;
-        }
+/// Counts the plots that can be reached in exactly @limit steps from @start
+fn reachable(grid: &Grid<char>, start: Coord, limit: usize) -> u64 {
+    let dist = bfs_distances(grid, start, |_, next| *next != '#');
 
-        if seen.contains(&(pos, path)) {
-            continue;
-        }
-
-        seen.insert((pos, path));
-
-        for t in get_adjacent(pos, grid) {
-            queue.push_back((t, path + 1));
-        }
-    }
+    // any plot reached in fewer steps can be reached again by stepping back and forth
+    dist.iter()
+        .filter(|(_, d)| matches!(d, Some(d) if *d <= limit && d % 2 == limit % 2))
+        .count() as u64
 }
 
 /// https://en.wikipedia.org/wiki/Divided_differences
@@ -128,61 +58,39 @@ fn poly_fit(points: &[(f64, f64)], x: f64) -> f64 {
 }
 
 /// https://www.reddit.com/r/adventofcode/comments/18nevo3/comment/keaiiq7/?utm_source=share&utm_medium=web3x&utm_name=web3xcss&utm_term=1&utm_content=share_button
-fn solution(input: &str, step_limit: usize) -> u64 {
-    let lines: Vec<_> = input.lines().collect();
-    let mut grid = Vec::new();
-    let mut start = (0, 0);
-
-    for (i, line) in lines.iter().enumerate() {
-        grid.push(Vec::new());
-
-        for (j, c) in line.char_indices() {
-            match c {
-                '#' => grid[i].push(Tile::new(TileType::Rock)),
-                '.' => grid[i].push(Tile::new(TileType::Plot)),
-                'S' => {
-                    start = (i, j);
-                    grid[i].push(Tile::new(TileType::Plot))
-                }
-                _ => panic!(),
-            }
-        }
+///
+/// Relies on the row and column through the start being clear, which holds for
+/// real inputs but not for the puzzle's example, so there's no example to check
+fn solution(grid: &Grid<char>, step_limit: usize) -> Result<u64, AocError> {
+    let start = grid
+        .find(&'S')
+        .ok_or_else(|| AocError::unsolvable("there's no start (S)"))?;
+
+    let grid_limit = grid.height();
+    if grid.height() != grid.width() {
+        return Err(AocError::unsolvable("the garden isn't square"));
     }
 
-    let grid_limit = grid.len();
-    assert!(grid.len() == grid[0].len());
-
     let remainder = step_limit % grid_limit;
-    assert!(remainder == 65);
+    if remainder != start.x as usize {
+        return Err(AocError::unsolvable(
+            "the steps left after crossing whole gardens don't reach the edge",
+        ));
+    }
 
     let mut fn_vals = [0, 0, 0];
 
     for (i, v) in fn_vals.iter_mut().enumerate() {
-        let mut fn_grid = grid.clone();
-
-        if i != 0 {
-            for r in 0..fn_grid.len() {
-                for _ in 0..(u64::pow(2, i as u32) as usize) {
-                    fn_grid[r].append(&mut grid[r].clone())
-                }
-            }
-
-            let temp = fn_grid.clone();
-
-            for _ in 0..(u64::pow(2, i as u32) as usize) {
-                fn_grid.append(&mut temp.clone());
-            }
+        // the garden repeated i times in every direction
+        let size = grid_limit * (2 * i + 1);
+        let mut fn_grid = Grid::new(size, size, '.');
+        for c in fn_grid.coords() {
+            fn_grid[c] = grid[grid.wrap(c)];
         }
-        assert!(fn_grid.len() == fn_grid[0].len());
 
         let search = grid_limit * i;
-        let start = (start.0 + search, start.1 + search);
-        bfs(start, remainder + search, &mut fn_grid);
-
-        *v = fn_grid
-            .iter()
-            .map(|v| v.iter().filter(|t| t.path == remainder + search).count())
-            .fold(0, |acc, s| acc + s as u64);
+        let start = start + Coord::new(search as i64, search as i64);
+        *v = reachable(&fn_grid, start, remainder + search);
     }
 
     let fn_vals: Vec<_> = fn_vals
@@ -191,5 +99,5 @@ fn solution(input: &str, step_limit: usize) -> u64 {
         .map(|(i, v)| (i as f64, *v as f64))
         .collect();
 
-    poly_fit(&fn_vals, (step_limit / grid_limit) as f64) as u64
+    Ok(poly_fit(&fn_vals, (step_limit / grid_limit) as f64) as u64)
 }
@@ -1,8 +1,9 @@
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::fs;
-use std::io;
 use std::ops::RangeInclusive;
 
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
+
 #[derive(Debug, Clone)]
 struct Block {
     id: usize,
@@ -100,12 +101,21 @@ impl Ord for Block {
     }
 }
 
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day22.txt")?;
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 22;
 
-    println!("solution: {}", solution(&input));
+    type Input<'a> = &'a str;
 
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input).into())
+    }
 }
 
 /*
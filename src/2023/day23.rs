@@ -1,11 +1,12 @@
 use std::{
     collections::{HashMap, HashSet},
     fmt::Display,
-    fs,
     hash::Hash,
-    io,
 };
 
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
+
 enum Direction {
     Up,
     Down,
@@ -83,12 +84,21 @@ impl Hash for Node {
     }
 }
 
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day23.txt")?;
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 23;
 
-    println!("solution: {}", solution(&input));
+    type Input<'a> = &'a str;
 
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input).into())
+    }
 }
 
 fn get_adjacent(current: (usize, usize), graph: &[Vec<TileType>]) -> Vec<(usize, usize)> {
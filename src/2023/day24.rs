@@ -1,6 +1,7 @@
 use nalgebra::{matrix, vector, Matrix3, Matrix6, Vector3};
-use std::fs;
-use std::io;
+
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
 
 #[derive(Debug)]
 struct Hailstone {
@@ -21,12 +22,21 @@ impl Hailstone {
     }
 }
 
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day24.txt")?;
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 24;
 
-    println!("solution: {}", solution(&input));
+    type Input<'a> = &'a str;
 
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input).into())
+    }
 }
 
 fn skew_matrix(v: &Vector3<f64>) -> Matrix3<f64> {
@@ -1,4 +1,7 @@
-use std::{fmt::Display, fs, io};
+use std::fmt::Display;
+
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 struct Node {
@@ -135,12 +138,21 @@ impl Display for Graph {
     }
 }
 
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day25.txt")?;
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 25;
 
-    println!("solution: {}", solution(&input));
+    type Input<'a> = &'a str;
 
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input).into())
+    }
 }
 
 // https://www.reddit.com/r/adventofcode/comments/18qbsxs/comment/ketzp94/?utm_source=share&utm_medium=web3x&utm_name=web3xcss&utm_term=1&utm_content=share_button
@@ -1,10 +1,21 @@
-use std::{fs, io};
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
 
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day3.txt")?;
+pub struct Day3;
 
-    println!("solution: {}", solution(&input));
-    Ok(())
+impl Solution for Day3 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 3;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input).into())
+    }
 }
 
 fn construct_numbers(line: &str, idx: usize) -> Option<Vec<u32>> {
@@ -1,5 +1,7 @@
 use std::thread;
-use std::{fs, io};
+
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
 
 #[derive(Debug, Clone, Copy)]
 struct MapEntry {
@@ -54,35 +56,51 @@ impl Map {
     }
 }
 
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day5.txt")?;
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 5;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
 
-    println!("solution: {}", solution(&input));
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input)?.into())
+    }
 }
 
-fn parse_line(line: &str) -> MapEntry {
+/// Parses a map line, which is @line_no in the input
+fn parse_line(line_no: usize, line: &str) -> Result<MapEntry, AocError> {
+    let invalid = || AocError::parse(line_no, 1, format!("invalid map entry \"{}\"", line));
     let nums: Vec<u64> = line
         .split_whitespace()
-        .map(|s| s.parse::<u64>().unwrap())
-        .collect();
+        .map(|s| s.parse::<u64>())
+        .collect::<Result<_, _>>()
+        .map_err(|_| invalid())?;
 
     if nums.len() != 3 {
-        println!("line: {}", line);
-        println!("nums: {:?}", nums);
-        panic!("Bad input!");
+        return Err(invalid());
     }
 
-    MapEntry::new(nums[1], nums[0], nums[2])
+    Ok(MapEntry::new(nums[1], nums[0], nums[2]))
 }
 
 fn find_location(seed: u64, maps: &[Map]) -> u64 {
     maps.iter().fold(seed, |id, e| e.get_mapping(id))
 }
 
-fn solution(input: &str) -> u64 {
-    let lines: Vec<&str> = input.lines().filter(|s| !s.trim().is_empty()).collect();
+fn solution(input: &str) -> Result<u64, AocError> {
+    let lines: Vec<(usize, &str)> = input
+        .lines()
+        .enumerate()
+        .filter(|(_, s)| !s.trim().is_empty())
+        .collect();
     let seeds: Vec<u64> = lines[0]
+        .1
         .split_whitespace()
         .skip(1)
         .map(|s| s.parse::<u64>().unwrap())
@@ -92,7 +110,7 @@ fn solution(input: &str) -> u64 {
     let mut map = Map::new();
     let mut iter = lines.iter().skip(1).peekable();
     while iter.peek().is_some() {
-        let line = iter.next().unwrap();
+        let (i, line) = iter.next().unwrap();
 
         if line.ends_with("map:") {
             if !map.entries.is_empty() {
@@ -103,7 +121,7 @@ fn solution(input: &str) -> u64 {
             continue;
         }
 
-        map.insert(parse_line(line));
+        map.insert(parse_line(i + 1, line)?);
     }
 
     if !map.entries.is_empty() {
@@ -133,5 +151,5 @@ fn solution(input: &str) -> u64 {
         locations.push(h.join().unwrap());
     }
 
-    *locations.iter().min().unwrap()
+    Ok(*locations.iter().min().unwrap())
 }
@@ -1,11 +1,26 @@
-use std::fs;
-use std::io;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Example, Solution};
 
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day6.txt")?;
+pub struct Day6;
 
-    println!("solution: {}", solution(&input));
-    Ok(())
+impl Solution for Day6 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 6;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day6_example.txt",
+        part1: None,
+        part2: Some("71503"),
+    }];
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input).into())
+    }
 }
 
 fn process_race(i: u64, race: (u64, u64)) -> Option<u64> {
@@ -1,7 +1,8 @@
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::fs;
-use std::io;
+
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Example, Solution};
 
 #[derive(Debug, Clone, Copy, Eq)]
 struct Hand<'a> {
@@ -63,13 +64,7 @@ impl<'a> Hand<'a> {
                     (4, 1) | (1, 4) => 6,
                     // full house
                     (3, 2) | (2, 3) => 5,
-                    _ => {
-                        println!(
-                            "Hand strength couldn't be calculated: {} map: {:?}",
-                            hand, map
-                        );
-                        0
-                    }
+                    _ => 0,
                 }
             }
             3 => {
@@ -82,13 +77,7 @@ impl<'a> Hand<'a> {
                     (3, 1) | (1, 3) | (1, 1) => 4,
                     // two pair
                     (1, 2) | (2, 1) | (2, 2) => 3,
-                    _ => {
-                        println!(
-                            "Hand strength couldn't be calculated: {} map: {:?}",
-                            hand, map
-                        );
-                        0
-                    }
+                    _ => 0,
                 }
             }
             4 => 2,
@@ -141,11 +130,26 @@ impl<'a> Ord for Hand<'a> {
     }
 }
 
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day7.txt")?;
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 7;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day7_example.txt",
+        part1: None,
+        part2: Some("5905"),
+    }];
+
+    type Input<'a> = &'a str;
 
-    println!("solution: {}", solution(&input));
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input).into())
+    }
 }
 
 fn solution(input: &str) -> u32 {
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
-use std::fs;
-use std::io;
+
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
 
 #[derive(Debug, Clone)]
 struct Node {
@@ -15,11 +16,21 @@ impl Node {
     }
 }
 
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day8.txt")?;
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 8;
 
-    println!("solution: {}", solution(&input));
-    Ok(())
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input).into())
+    }
 }
 
 fn solution(input: &str) -> u32 {
@@ -56,8 +67,7 @@ fn solution(input: &str) -> u32 {
 
     let mut path_steps: Vec<u64> = Vec::new();
     for s in start.iter_mut() {
-        let mut steps = 1;
-        for i in instructions.chars().cycle() {
+        for (steps, i) in (1..).zip(instructions.chars().cycle()) {
             let next = if i == 'L' { &s.left } else { &s.right };
             *s = graph.get(next).unwrap().clone();
 
@@ -65,8 +75,6 @@ fn solution(input: &str) -> u32 {
                 path_steps.push(steps);
                 break;
             }
-
-            steps += 1;
         }
     }
 
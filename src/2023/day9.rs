@@ -1,11 +1,26 @@
-use std::fs;
-use std::io;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Example, Solution};
 
-pub fn run() -> Result<(), io::Error> {
-    let input = fs::read_to_string("inputs/2023/day9.txt")?;
+pub struct Day9;
 
-    println!("solution: {}", solution(&input));
-    Ok(())
+impl Solution for Day9 {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 9;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day9_example.txt",
+        part1: None,
+        part2: Some("2"),
+    }];
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solution(input).into())
+    }
 }
 
 fn compute_derivative(line: Vec<i32>) -> Vec<Vec<i32>> {
@@ -1,8 +1,7 @@
 use std::collections::HashMap;
-use std::fs;
-use std::io;
 
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Example, Solution};
 
 fn part1(input: &str) -> u64 {
     let mut left = Vec::new();
@@ -55,10 +54,28 @@ fn part2(input: &str) -> i64 {
     map.values().sum::<i64>()
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2024/day1.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day1;
 
-    Ok(())
+impl Solution for Day1 {
+    const YEAR: u32 = 2024;
+    const DAY: usize = 1;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day1_example.txt",
+        part1: Some("11"),
+        part2: Some("31"),
+    }];
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
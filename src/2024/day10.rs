@@ -1,25 +1,15 @@
-use std::collections::HashSet;
-use std::fs;
-use std::io;
-
 use aocutils::coord::Coord;
+use aocutils::error::AocError;
 use aocutils::graph::Graph;
-use aocutils::grid::direction::*;
-use aocutils::grid::in_bounds;
-use aocutils::timeln;
-
-fn can_traverse(grid: &[Vec<u32>], curr: Coord, next: Coord) -> bool {
-    if !in_bounds(grid, next) {
-        return false;
-    }
-
-    let (x, y) = curr.as_unsigned().unwrap();
-    let (nx, ny) = next.as_unsigned().unwrap();
+use aocutils::grid::algo::bfs_distances;
+use aocutils::grid::{Connectivity, Grid};
+use aocutils::solution::{Answer, Example, Solution};
 
-    grid[x][y] + 1 == grid[nx][ny]
+fn uphill(from: &u32, to: &u32) -> bool {
+    to.checked_sub(*from) == Some(1)
 }
 
-fn build_graph(grid: &[Vec<u32>], head: Coord) -> Graph<(u32, Coord)> {
+fn build_graph(grid: &Grid<u32>, head: Coord) -> Graph<(u32, Coord)> {
     let mut graph = Graph::new();
     let mut to_visit = Vec::new();
 
@@ -27,15 +17,12 @@ fn build_graph(grid: &[Vec<u32>], head: Coord) -> Graph<(u32, Coord)> {
     to_visit.push((head, hv));
 
     while let Some((curr, v)) = to_visit.pop() {
-        for dir in DIRECTIONS {
-            let next = curr + dir.into();
-
-            if can_traverse(grid, curr, next) {
-                let (nx, ny) = next.as_unsigned().unwrap();
-                let v2 = graph.add_vertex((grid[nx][ny], next), None);
+        for (next, height) in grid.neighbors(curr, Connectivity::Four) {
+            if uphill(&grid[curr], height) {
+                let v2 = graph.add_vertex((*height, next), None);
                 graph.add_edge(v, v2, 1);
 
-                if grid[nx][ny] < 9 {
+                if *height < 9 {
                     to_visit.push((next, v2));
                 }
             }
@@ -45,82 +32,51 @@ fn build_graph(grid: &[Vec<u32>], head: Coord) -> Graph<(u32, Coord)> {
     graph
 }
 
-fn part1(input: &str) -> usize {
-    let mut grid = Vec::new();
-    let mut heads = Vec::new();
-
-    for (i, line) in input.lines().enumerate() {
-        grid.push(Vec::new());
-
-        for (j, n) in line
-            .chars()
-            .map(|c| c.to_digit(10).unwrap_or(u32::MAX))
-            .enumerate()
-        {
-            if n == 0 {
-                heads.push(Coord::new(i as i64, j as i64));
-            }
-
-            grid[i].push(n);
-        }
-    }
+fn part1(grid: &Grid<u32>) -> usize {
+    grid.positions(&0)
+        .map(|head| {
+            let dist = bfs_distances(grid, head, uphill);
+            grid.positions(&9).filter(|c| dist[*c].is_some()).count()
+        })
+        .sum()
+}
 
+fn part2(grid: &Grid<u32>) -> usize {
     let mut cnt = 0;
-    for h in heads {
-        let mut seen = HashSet::new();
-        let graph = build_graph(&grid, h);
-
-        for (_, coord) in graph
+    for h in grid.positions(&0) {
+        let graph = build_graph(grid, h);
+        cnt += graph
             .iter()
             .filter(|v| v.data.0 == 9)
-            .map(|v| v.data)
-        {
-            seen.insert(coord);
-        }
-
-        cnt += seen.len();
+            .count();
     }
 
     cnt
 }
 
-fn part2(input: &str) -> usize {
-    let mut grid = Vec::new();
-    let mut heads = Vec::new();
+pub struct Day10;
 
-    for (i, line) in input.lines().enumerate() {
-        grid.push(Vec::new());
+impl Solution for Day10 {
+    const YEAR: u32 = 2024;
+    const DAY: usize = 10;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day10_example.txt",
+        part1: Some("36"),
+        part2: Some("81"),
+    }];
 
-        for (j, n) in line
-            .chars()
-            .map(|c| c.to_digit(10).unwrap_or(u32::MAX))
-            .enumerate()
-        {
-            if n == 0 {
-                heads.push(Coord::new(i as i64, j as i64));
-            }
+    type Input<'a> = Grid<u32>;
 
-            grid[i].push(n);
-        }
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        // impassable tiles in the examples are marked with .
+        Grid::parse(input, |c| c.to_digit(10).unwrap_or(u32::MAX))
     }
 
-    let mut cnt = 0;
-    for h in heads {
-        let graph = build_graph(&grid, h);
-        cnt += graph
-            .iter()
-            .filter(|v| v.data.0 == 9)
-            .count();
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
     }
 
-    cnt
-}
-
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2024/day10.txt")?;
-
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
-
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
@@ -1,8 +1,7 @@
 use std::collections::HashMap;
-use std::fs;
-use std::io;
 
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Example, Solution};
 
 fn calc_stone(stone: u64) -> (u64, Option<u64>) {
     match stone {
@@ -76,11 +75,28 @@ fn part2(input: &str) -> u64 {
         .sum::<u64>()
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2024/day11.txt")?;
+pub struct Day11;
 
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+impl Solution for Day11 {
+    const YEAR: u32 = 2024;
+    const DAY: usize = 11;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day11_example.txt",
+        part1: Some("55312"),
+        part2: None,
+    }];
 
-    Ok(())
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
@@ -1,8 +1,6 @@
-use std::fs;
-use std::io;
-
 use aocutils::coord::Coord;
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
 
 fn determinant(a: Coord, b: Coord) -> i64 {
     a.x * b.y - b.x * a.y
@@ -96,10 +94,23 @@ fn part2(input: &str) -> i64 {
     cnt
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2024/day13.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u32 = 2024;
+    const DAY: usize = 13;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
@@ -1,11 +1,12 @@
 use std::collections::HashSet;
-use std::fs;
-use std::io;
 
 use aocutils::coord::Coord;
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::grid;
+use aocutils::solution::{Answer, Solution};
 
-struct Robot {
+#[derive(Clone)]
+pub struct Robot {
     pos: Coord,
     vel: Coord,
 }
@@ -16,49 +17,44 @@ impl Robot {
     }
 
     fn simulate(&mut self, (xbound, ybound): (usize, usize), seconds: usize) {
-        let (xbound, ybound) = (xbound as i64, ybound as i64);
-        if xbound < 0 || ybound < 0 {
-            return;
-        }
-
-        for _ in 0..seconds {
-            self.pos += self.vel;
-
-            self.pos.x = match self.pos.x {
-                x if x < 0 => xbound + x,
-                x if x >= xbound => x - xbound,
-                _ => self.pos.x,
-            };
-
-            self.pos.y = match self.pos.y {
-                y if y < 0 => ybound + y,
-                y if y >= ybound => y - ybound,
-                _ => self.pos.y,
-            };
-        }
+        self.pos = grid::wrap(
+            self.pos + self.vel.mult_scalar(seconds as i64),
+            ybound,
+            xbound,
+        );
     }
 }
 
-fn part1(input: &str) -> usize {
-    let mut robots = Vec::new();
-    let (rows, cols) = (103, 101);
-
-    for line in input.lines() {
-        let coords: Vec<Vec<i64>> = line
-            .split_whitespace()
-            .map(|s| s.split('=').nth(1).unwrap())
-            .map(|s| {
-                s.split(',')
-                    .map(|d| d.parse::<i64>().unwrap())
-                    .collect::<Vec<i64>>()
-            })
-            .collect();
-
-        robots.push(Robot::new(
-            Coord::new(coords[0][1], coords[0][0]),
-            Coord::new(coords[1][1], coords[1][0]),
-        ));
-    }
+/// Parses lines like "p=0,4 v=3,-3". Positions can't be negative, since they're
+/// in the room
+fn robots(input: &str) -> Result<Vec<Robot>, AocError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let invalid = || AocError::parse(i + 1, 1, format!("invalid robot \"{}\"", line));
+            let numbers: Vec<i64> = line
+                .split(|c: char| !(c.is_ascii_digit() || c == '-'))
+                .filter(|s| !s.is_empty())
+                .map(|s| s.parse())
+                .collect::<Result<_, _>>()
+                .map_err(|_| invalid())?;
+
+            match numbers[..] {
+                [px, py, vx, vy] if px >= 0 && py >= 0 => {
+                    Ok(Robot::new(Coord::new(py, px), Coord::new(vy, vx)))
+                }
+                _ => Err(invalid()),
+            }
+        })
+        .collect()
+}
+
+/// Rows and columns in the room the robots are in
+const ROOM: (usize, usize) = (103, 101);
+
+fn part1(robots: &[Robot], (rows, cols): (usize, usize)) -> usize {
+    let mut robots = robots.to_vec();
 
     for r in robots.iter_mut() {
         r.simulate((rows, cols), 100);
@@ -86,30 +82,13 @@ fn part1(input: &str) -> usize {
     quads.iter().product::<usize>()
 }
 
-fn part2(input: &str) -> i32 {
-    let mut robots = Vec::new();
-    let (rows, cols) = (103, 101);
-
-    for line in input.lines() {
-        let coords: Vec<Vec<i64>> = line
-            .split_whitespace()
-            .map(|s| s.split('=').nth(1).unwrap())
-            .map(|s| {
-                s.split(',')
-                    .map(|d| d.parse::<i64>().unwrap())
-                    .collect::<Vec<i64>>()
-            })
-            .collect();
-
-        robots.push(Robot::new(
-            Coord::new(coords[0][1], coords[0][0]),
-            Coord::new(coords[1][1], coords[1][0]),
-        ));
-    }
+fn part2(robots: &[Robot], (rows, cols): (usize, usize)) -> Result<usize, AocError> {
+    let mut robots = robots.to_vec();
 
+    // robots are back where they started after rows * cols seconds
     let mut i = 0;
     let mut found = false;
-    loop {
+    while i < rows * cols {
         for r in robots.iter_mut() {
             r.simulate((rows, cols), 1);
         }
@@ -138,13 +117,43 @@ fn part2(input: &str) -> i32 {
         i += 1;
     }
 
-    i + 1
+    if found {
+        Ok(i + 1)
+    } else {
+        Err(AocError::unsolvable("the robots never form a tree"))
+    }
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2024/day14.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day14;
 
-    Ok(())
+impl Solution for Day14 {
+    const YEAR: u32 = 2024;
+    const DAY: usize = 14;
+
+    type Input<'a> = Vec<Robot>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        robots(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input, ROOM).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input, ROOM)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        // the example's room is only 7 by 11
+        let robots = robots(include_str!("../../inputs/2024/day14_example.txt")).unwrap();
+
+        assert_eq!(part1(&robots, (7, 11)), 12);
+    }
 }
@@ -1,11 +1,10 @@
 use std::fmt::Display;
-use std::fs;
-use std::io;
 
 use aocutils::coord::Coord;
+use aocutils::error::AocError;
 use aocutils::grid::direction::GridDirection;
 use aocutils::grid::in_bounds;
-use aocutils::timeln;
+use aocutils::solution::{Answer, Solution};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum Cell {
@@ -304,10 +303,23 @@ fn part2(input: &str) -> usize {
         .sum::<usize>()
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2024/day15.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day15;
 
-    Ok(())
+impl Solution for Day15 {
+    const YEAR: u32 = 2024;
+    const DAY: usize = 15;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
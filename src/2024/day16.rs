@@ -1,224 +1,128 @@
-use std::collections::HashMap;
 use std::collections::HashSet;
-use std::fs;
-use std::io;
 
 use aocutils::coord::Coord;
-use aocutils::graph::*;
-use aocutils::grid::direction::GridDirection;
-use aocutils::grid::direction::DIRECTIONS;
-use aocutils::grid::in_bounds;
-use aocutils::timeln;
-
-fn insert_node(
-    coord: Coord,
-    id_map: &mut HashMap<Coord, Vec<GraphID>>,
-    graph: &mut Graph<(Coord, GridDirection)>,
-) {
-    use GridDirection::*;
-
-    let mut nodes = Vec::new();
-    for d in DIRECTIONS {
-        nodes.push((
-            d,
-            graph.add_vertex(
-                (coord, d),
-                Some(&format!(
-                    "{}{}",
-                    coord,
-                    match d {
-                        Up => 'N',
-                        Down => 'S',
-                        Left => 'W',
-                        Right => 'E',
-                    }
-                )),
-            ),
-        ));
-    }
-
-    for (dir, id) in &nodes {
-        let adj = (dir.rotate_left(), dir.rotate_right());
-
-        for (_, id2) in nodes.iter().filter(|(d, _)| *d == adj.0 || *d == adj.1) {
-            graph.add_edge(*id, *id2, 1000);
-        }
-    }
-
-    id_map.insert(coord, nodes.into_iter().map(|(_, id)| id).collect());
+use aocutils::error::AocError;
+use aocutils::grid::Grid;
+use aocutils::grid::algo::{search, search_costs};
+use aocutils::grid::direction::{DIRECTIONS, GridDirection};
+use aocutils::solution::{Answer, Example, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Reindeer {
+    pos: Coord,
+    dir: GridDirection,
 }
 
-fn connect_nodes(
-    coord: Coord,
-    grid: &[Vec<char>],
-    id_map: &HashMap<Coord, Vec<GraphID>>,
-    graph: &mut Graph<(Coord, GridDirection)>,
-) {
-    for dir in DIRECTIONS.iter() {
-        let vid = id_map
-            .get(&coord)
-            .unwrap()
-            .iter()
-            .find(|id| graph.get_vertex(**id).unwrap().data.1 == *dir)
-            .unwrap();
-        let c = coord + (*dir).into();
-
-        if !in_bounds(grid, c) {
-            continue;
-        }
+impl Reindeer {
+    /// Gets where this Reindeer can get to by stepping @forward (or backward) or
+    /// turning, along with the points that costs
+    fn moves(&self, grid: &Grid<char>, forward: bool) -> Vec<(Reindeer, usize)> {
+        let mut moves = vec![
+            (
+                Reindeer {
+                    dir: self.dir.rotate_left(),
+                    ..*self
+                },
+                1000,
+            ),
+            (
+                Reindeer {
+                    dir: self.dir.rotate_right(),
+                    ..*self
+                },
+                1000,
+            ),
+        ];
 
-        let (x, y) = c.as_unsigned().unwrap();
-        if grid[x][y] == '#' {
-            continue;
-        }
+        let step: Coord = self.dir.into();
+        let pos = if forward {
+            self.pos + step
+        } else {
+            self.pos - step
+        };
 
-        let adj = id_map.get(&c).unwrap();
-        let aid = adj
-            .iter()
-            .find(|aid| graph.get_vertex(**aid).unwrap().data.1 == *dir)
-            .unwrap();
+        if grid.get(pos).is_some_and(|c| *c != '#') {
+            moves.push((Reindeer { pos, ..*self }, 1));
+        }
 
-        graph.add_edge(*vid, *aid, 1);
+        moves
     }
 }
 
-fn part1(input: &str) -> i64 {
-    let mut grid = Vec::new();
-    let mut start = Coord::new(0, 0);
-    let mut end = Coord::new(0, 0);
-
-    for (i, line) in input.lines().enumerate() {
-        grid.push(Vec::new());
-
-        for (j, ch) in line.char_indices() {
-            match ch {
-                'S' => {
-                    start = Coord::new(i as i64, j as i64);
-                    grid[i].push(ch);
-                }
-                'E' => {
-                    end = Coord::new(i as i64, j as i64);
-                    grid[i].push(ch);
-                }
-                _ => grid[i].push(ch),
-            }
-        }
-    }
-
-    let mut graph = Graph::new();
-    let mut id_map = HashMap::new();
-
-    for (i, row) in grid.iter().enumerate() {
-        for (j, ch) in row.iter().enumerate() {
-            if *ch == '#' {
-                continue;
-            }
+pub struct Maze {
+    grid: Grid<char>,
+    start: Reindeer,
+    end: Coord,
+}
 
-            insert_node(Coord::new(i as i64, j as i64), &mut id_map, &mut graph);
-        }
-    }
+fn maze(input: &str) -> Result<Maze, AocError> {
+    let grid = Grid::parse(input, |c| c)?;
+    let start = Reindeer {
+        pos: grid
+            .find(&'S')
+            .ok_or_else(|| AocError::unsolvable("there's no start tile (S)"))?,
+        dir: GridDirection::Right,
+    };
+    let end = grid
+        .find(&'E')
+        .ok_or_else(|| AocError::unsolvable("there's no end tile (E)"))?;
+
+    Ok(Maze { grid, start, end })
+}
 
-    for k in id_map.keys() {
-        connect_nodes(*k, &grid, &id_map, &mut graph);
-    }
+fn part1(maze: &Maze) -> Result<usize, AocError> {
+    search(
+        [maze.start],
+        |r| r.moves(&maze.grid, true),
+        |r| r.pos == maze.end,
+    )
+    .map(|(score, _)| score)
+    .ok_or_else(|| AocError::unsolvable("the end can't be reached"))
+}
 
-    let start = id_map
-        .get(&start)
-        .unwrap()
+fn part2(maze: &Maze) -> Result<usize, AocError> {
+    // a tile is on a best path if the cost to it plus the cost from it to the end
+    // is the best score
+    let to = search_costs([maze.start], |r| r.moves(&maze.grid, true));
+    let ends = DIRECTIONS.map(|dir| Reindeer { pos: maze.end, dir });
+    let from = search_costs(ends, |r| r.moves(&maze.grid, false));
+    let best = ends
         .iter()
-        .find(|id| graph.get_vertex(**id).unwrap().data.1 == GridDirection::Right)
-        .unwrap();
-    let paths = graph.djikstra(*start);
+        .filter_map(|r| to.get(r))
+        .min()
+        .ok_or_else(|| AocError::unsolvable("the end can't be reached"))?;
 
-    id_map
-        .get(&end)
-        .unwrap()
+    let tiles: HashSet<Coord> = to
         .iter()
-        .map(|eid| {
-            paths
-                .iter()
-                .find(|(vid, _)| *vid == *eid)
-                .unwrap_or(&(0, 0))
-                .1
-        })
-        .min()
-        .unwrap()
+        .filter(|(r, cost)| from.get(r).is_some_and(|c| *cost + c == *best))
+        .map(|(r, _)| r.pos)
+        .collect();
+
+    Ok(tiles.len())
 }
 
-fn part2(input: &str) -> usize {
-    let mut grid = Vec::new();
-    let mut start = Coord::new(0, 0);
-    let mut end = Coord::new(0, 0);
-
-    for (i, line) in input.lines().enumerate() {
-        grid.push(Vec::new());
-
-        for (j, ch) in line.char_indices() {
-            match ch {
-                'S' => {
-                    start = Coord::new(i as i64, j as i64);
-                    grid[i].push(ch);
-                }
-                'E' => {
-                    end = Coord::new(i as i64, j as i64);
-                    grid[i].push(ch);
-                }
-                _ => grid[i].push(ch),
-            }
-        }
-    }
+pub struct Day16;
 
-    let mut graph = Graph::new();
-    let mut id_map = HashMap::new();
+impl Solution for Day16 {
+    const YEAR: u32 = 2024;
+    const DAY: usize = 16;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day16_example.txt",
+        part1: Some("7036"),
+        part2: Some("45"),
+    }];
 
-    for (i, row) in grid.iter().enumerate() {
-        for (j, ch) in row.iter().enumerate() {
-            if *ch == '#' {
-                continue;
-            }
+    type Input<'a> = Maze;
 
-            insert_node(Coord::new(i as i64, j as i64), &mut id_map, &mut graph);
-        }
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        maze(input)
     }
 
-    for k in id_map.keys() {
-        connect_nodes(*k, &grid, &id_map, &mut graph);
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input)?.into())
     }
 
-    let start = id_map
-        .get(&start)
-        .unwrap()
-        .iter()
-        .find(|id| graph.get_vertex(**id).unwrap().data.1 == GridDirection::Right)
-        .unwrap();
-    let paths = graph.djikstra(*start);
-    let (mut seid, mut min) = (0, i64::MAX);
-
-    for eid in id_map.get(&end).unwrap() {
-        for (id, cost) in paths.iter().filter(|(id, _)| *id == *eid) {
-            if *cost < min {
-                seid = *id;
-                min = *cost;
-            }
-        }
-    }
-
-    let mut set = HashSet::new();
-    let paths = graph.djikstra_all_paths(*start, seid);
-
-    for path in paths {
-        for v in path.iter().map(|id| graph.get_vertex(*id).unwrap()) {
-            set.insert(v.data.0);
-        }
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input)?.into())
     }
-
-    set.len()
-}
-
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2024/day16.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
-
-    Ok(())
 }
@@ -1,102 +1,145 @@
-use std::fs;
-use std::io;
-
 use aocutils::coord::Coord;
+use aocutils::error::AocError;
+use aocutils::grid::Grid;
 use aocutils::grid::algo::*;
-use aocutils::timeln;
+use aocutils::parse;
+use aocutils::solution::{Answer, Solution};
+
+/// Gets the positions of the falling bytes
+fn bytes(input: &str) -> Result<Vec<Coord>, AocError> {
+    parse::numbers::<usize>(input)?
+        .into_iter()
+        .enumerate()
+        .map(|(i, coords)| match coords[..] {
+            [x, y] => Ok(Coord::new(y as i64, x as i64)),
+            _ => Err(AocError::parse(
+                i + 1,
+                1,
+                format!("expected 2 coordinates, found {}", coords.len()),
+            )),
+        })
+        .collect()
+}
 
-static GRIDSIZE: usize = 71;
-static TAKE_BYTES: usize = 1024;
+/// Width and height of the memory space
+const SIZE: usize = 71;
+/// Bytes that have fallen before part 1
+const FALLEN: usize = 1024;
+
+/// Gets an empty @size by @size memory space, checking that all of @bytes fall
+/// inside of it
+fn memory_space(bytes: &[Coord], size: usize) -> Result<Grid<bool>, AocError> {
+    let grid = Grid::new(size, size, false);
+
+    match bytes.iter().position(|c| grid.get(*c).is_none()) {
+        Some(i) => Err(AocError::parse(
+            i + 1,
+            1,
+            format!(
+                "{},{} is outside of the memory space",
+                bytes[i].y, bytes[i].x
+            ),
+        )),
+        None => Ok(grid),
+    }
+}
 
-fn cost_fn(grid: &[Vec<char>], _current: Coord, curr_cost: usize, next: Coord) -> usize {
-    let (nx, ny) = next.as_unsigned().unwrap();
+/// Gets the number of steps from the top left to the bottom right of @grid, where
+/// true cells are corrupted
+fn escape(grid: &Grid<bool>) -> Option<usize> {
+    let end = Coord::new(grid.height() as i64 - 1, grid.width() as i64 - 1);
 
-    if grid[nx][ny] == '#' {
-        usize::MAX
-    } else {
-        curr_cost + 1
-    }
+    bfs_distances(grid, Coord::new(0, 0), |_, corrupted| !corrupted)[end]
 }
 
-fn part1(input: &str) -> usize {
-    let mut grid = vec![vec!['.'; GRIDSIZE]; GRIDSIZE];
+fn part1(bytes: &[Coord], size: usize, fallen: usize) -> Result<usize, AocError> {
+    let mut grid = memory_space(bytes, size)?;
 
-    for line in input.lines().take(TAKE_BYTES) {
-        let coords: Vec<usize> = line
-            .split(',')
-            .map(|d| d.parse::<usize>().unwrap())
-            .collect();
-        grid[coords[1]][coords[0]] = '#';
+    for c in bytes.iter().take(fallen) {
+        grid[*c] = true;
     }
 
-    djikstra(
-        &grid,
-        Coord::new(0, 0),
-        Coord::new(GRIDSIZE as i64 - 1, GRIDSIZE as i64 - 1),
-        cost_fn,
-    )
-    .unwrap()
-    .len()
-        - 1
+    escape(&grid).ok_or_else(|| AocError::unsolvable("the exit is already cut off"))
 }
 
-fn binary_search(grid: &[Vec<char>], corrupted: &[(usize, usize)]) -> usize {
-    let (mut left, mut right) = (0, corrupted.len() - 1);
-    let mut middle = 1;
+/// Gets the first of @corrupted that cuts the exit off once it falls on @grid,
+/// along with the ones before it
+fn first_blocking(grid: &Grid<bool>, corrupted: &[Coord]) -> Result<Coord, AocError> {
+    let blocked = |fallen: usize| {
+        let mut g = grid.clone();
+        for c in &corrupted[..fallen] {
+            g[*c] = true;
+        }
 
-    while left <= right {
-        middle = (left + right) / 2;
+        escape(&g).is_none()
+    };
 
-        let mut g = grid.to_owned();
-        for (x, y) in corrupted.iter().take(middle) {
-            g[*x][*y] = '#';
-        }
+    if blocked(0) {
+        return Err(AocError::unsolvable(
+            "the exit is already cut off before any more bytes fall",
+        ));
+    } else if !blocked(corrupted.len()) {
+        return Err(AocError::unsolvable("no byte cuts the exit off"));
+    }
 
-        let exists = djikstra(
-            &g,
-            Coord::new(0, 0),
-            Coord::new(GRIDSIZE as i64 - 1, GRIDSIZE as i64 - 1),
-            cost_fn,
-        )
-        .is_some();
-
-        if exists && left == right {
-            return middle;
-        } else if exists {
-            left = middle + 1;
+    // find the fewest fallen bytes that block the exit
+    let (mut left, mut right) = (1, corrupted.len());
+    while left < right {
+        let middle = (left + right) / 2;
+
+        if blocked(middle) {
+            right = middle;
         } else {
-            right = middle - 1;
+            left = middle + 1;
         }
     }
 
-    middle - 1
+    Ok(corrupted[right - 1])
 }
 
-fn part2(input: &str) -> String {
-    let mut grid = vec![vec!['.'; GRIDSIZE]; GRIDSIZE];
-    let mut corrupted = Vec::new();
+fn part2(bytes: &[Coord], size: usize, fallen: usize) -> Result<String, AocError> {
+    let mut grid = memory_space(bytes, size)?;
+    let (fallen, corrupted) = bytes.split_at(fallen.min(bytes.len()));
 
-    for line in input.lines() {
-        let coords: Vec<usize> = line
-            .split(',')
-            .map(|d| d.parse::<usize>().unwrap())
-            .collect();
-        corrupted.push((coords[1], coords[0]));
+    for c in fallen {
+        grid[*c] = true;
     }
 
-    for (x, y) in corrupted.drain(0..TAKE_BYTES) {
-        grid[x][y] = '#';
+    let c = first_blocking(&grid, corrupted)?;
+    Ok(format!("{},{}", c.y, c.x))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u32 = 2024;
+    const DAY: usize = 18;
+
+    type Input<'a> = Vec<Coord>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        bytes(input)
     }
 
-    let idx = binary_search(&grid, &corrupted);
-    let c = corrupted[idx];
-    format!("{},{}", c.1, c.0)
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input, SIZE, FALLEN)?.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input, SIZE, FALLEN)?.into())
+    }
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2024/day18.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    Ok(())
+    #[test]
+    fn example() {
+        // the example's memory space is only 7 wide, with 12 bytes fallen
+        let bytes = bytes(include_str!("../../inputs/2024/day18_example.txt")).unwrap();
+
+        assert_eq!(part1(&bytes, 7, 12).unwrap(), 22);
+        assert_eq!(part2(&bytes, 7, 12).unwrap(), "6,1");
+    }
 }
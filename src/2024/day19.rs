@@ -1,8 +1,7 @@
 use std::collections::HashMap;
-use std::fs;
-use std::io;
 
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Example, Solution};
 
 fn is_possible(haystack: &Vec<&str>, needle: &str) -> bool {
     if needle.is_empty() {
@@ -71,10 +70,28 @@ fn part2(input: &str) -> usize {
         .sum::<usize>()
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2024/day19.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day19;
 
-    Ok(())
+impl Solution for Day19 {
+    const YEAR: u32 = 2024;
+    const DAY: usize = 19;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day19_example.txt",
+        part1: Some("6"),
+        part2: Some("16"),
+    }];
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
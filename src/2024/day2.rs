@@ -1,7 +1,6 @@
-use std::fs;
-use std::io;
-
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::parse;
+use aocutils::solution::{Answer, Example, Solution};
 
 fn check_safety(level: &[i64]) -> bool {
     let increasing = level[0] < level[1];
@@ -17,33 +16,14 @@ fn check_safety(level: &[i64]) -> bool {
     true
 }
 
-fn part1(input: &str) -> usize {
-    let mut levels: Vec<Vec<i64>> = Vec::new();
-
-    for line in input.lines() {
-        levels.push(
-            line.split_whitespace()
-                .map(|d| d.parse::<i64>().unwrap())
-                .collect(),
-        );
-    }
-
-        levels.iter().filter(|l| check_safety(l)).count()
+fn part1(levels: &[Vec<i64>]) -> usize {
+    levels.iter().filter(|l| check_safety(l)).count()
 }
 
-fn part2(input: &str) -> i32 {
-    let mut levels: Vec<Vec<i64>> = Vec::new();
+fn part2(levels: &[Vec<i64>]) -> i32 {
     let mut count = 0;
 
-    for line in input.lines() {
-        levels.push(
-            line.split_whitespace()
-                .map(|d| d.parse::<i64>().unwrap())
-                .collect(),
-        );
-    }
-
-    for l in levels.iter_mut() {
+    for l in levels {
         let mut safe = false;
 
         for i in 0..(l.len()) {
@@ -64,10 +44,28 @@ fn part2(input: &str) -> i32 {
     count
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2024/day2.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u32 = 2024;
+    const DAY: usize = 2;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day2_example.txt",
+        part1: Some("2"),
+        part2: Some("4"),
+    }];
 
-    Ok(())
+    type Input<'a> = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse::numbers(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
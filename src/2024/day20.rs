@@ -1,33 +1,30 @@
-use std::fs;
-use std::io;
-
 use aocutils::coord::Coord;
-use aocutils::grid::algo::djikstra;
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::grid::Grid;
+use aocutils::grid::algo::bfs_distances;
+use aocutils::solution::{Answer, Solution};
 
-fn cost_fn(grid: &[Vec<bool>], _curr: Coord, cost: usize, next: Coord) -> usize {
-    let (x, y) = next.as_unsigned().unwrap();
-    if grid[x][y] {
-        usize::MAX
-    } else {
-        cost + 1
-    }
-}
+/// Picoseconds a cheat has to save to be counted
+const TIME_SAVE: usize = 100;
+
+fn cheats(grid: &Grid<char>, cheat_len: usize, time_save: usize) -> Result<usize, AocError> {
+    let start = grid
+        .find(&'S')
+        .ok_or_else(|| AocError::unsolvable("there's no start (S)"))?;
+    let dist = bfs_distances(grid, start, |_, next| *next != '#');
 
-fn cheats(
-    grid: &[Vec<bool>],
-    start: Coord,
-    end: Coord,
-    cheat_len: usize,
-    time_save: usize,
-) -> usize {
-    let shortest = djikstra(grid, start, end, cost_fn).unwrap();
+    // there's only one track, so ordering its cells by distance gives the race
+    let mut track: Vec<(usize, Coord)> =
+        dist.iter().filter_map(|(c, d)| d.map(|d| (d, c))).collect();
+    track.sort();
+
+    let shortest: Vec<Coord> = track.into_iter().map(|(_, c)| c).collect();
     let len = shortest.len();
 
-    shortest
+    Ok(shortest
         .iter()
         .enumerate()
-        .take(len - time_save)
+        .take(len.saturating_sub(time_save))
         .map(|(i, c0)| {
             shortest
                 .iter()
@@ -39,67 +36,40 @@ fn cheats(
                 })
                 .count()
         })
-        .sum::<usize>()
+        .sum())
 }
 
-fn part1(input: &str) -> usize {
-    let mut grid = Vec::new();
-    let mut start = Coord::new(0, 0);
-    let mut end = Coord::new(0, 0);
+pub struct Day20;
 
-    for (i, line) in input.lines().enumerate() {
-        grid.push(Vec::new());
+impl Solution for Day20 {
+    const YEAR: u32 = 2024;
+    const DAY: usize = 20;
 
-        for (j, ch) in line.char_indices() {
-            match ch {
-                'S' => {
-                    start = Coord::new(i as i64, j as i64);
-                    grid[i].push(false);
-                }
-                'E' => {
-                    end = Coord::new(i as i64, j as i64);
-                    grid[i].push(false);
-                }
-                '.' => grid[i].push(false),
-                _ => grid[i].push(true),
-            }
-        }
-    }
+    type Input<'a> = Grid<char>;
 
-    cheats(&grid, start, end, 2, 100)
-}
-
-fn part2(input: &str) -> usize {
-    let mut grid = Vec::new();
-    let mut start = Coord::new(0, 0);
-    let mut end = Coord::new(0, 0);
-
-    for (i, line) in input.lines().enumerate() {
-        grid.push(Vec::new());
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Grid::parse(input, |c| c)
+    }
 
-        for (j, ch) in line.char_indices() {
-            match ch {
-                'S' => {
-                    start = Coord::new(i as i64, j as i64);
-                    grid[i].push(false);
-                }
-                'E' => {
-                    end = Coord::new(i as i64, j as i64);
-                    grid[i].push(false);
-                }
-                '.' => grid[i].push(false),
-                _ => grid[i].push(true),
-            }
-        }
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(cheats(input, 2, TIME_SAVE)?.into())
     }
 
-    cheats(&grid, start, end, 20, 100)
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(cheats(input, 20, TIME_SAVE)?.into())
+    }
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2024/day20.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        // nothing on the example's short track saves 100 picoseconds
+        let grid = Grid::parse(include_str!("../../inputs/2024/day20_example.txt"), |c| c).unwrap();
 
-    Ok(())
+        assert_eq!(cheats(&grid, 2, 50).unwrap(), 1);
+        assert_eq!(cheats(&grid, 20, 50).unwrap(), 285);
+    }
 }
@@ -1,11 +1,10 @@
 use std::collections::HashMap;
-use std::fs;
-use std::io;
 
 use aocutils::coord::Coord;
+use aocutils::error::AocError;
 use aocutils::graph::*;
 use aocutils::grid::direction::GridDirection;
-use aocutils::timeln;
+use aocutils::solution::{Answer, Solution};
 
 type PathMap = HashMap<(u8, u8), Vec<Vec<u8>>>;
 
@@ -239,12 +238,25 @@ fn part2(input: &str) -> usize {
     complexity
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2024/day21.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day21;
 
-    Ok(())
+impl Solution for Day21 {
+    const YEAR: u32 = 2024;
+    const DAY: usize = 21;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
 
 #[cfg(test)]
@@ -264,7 +276,6 @@ mod tests {
 
         let mut len = 0;
         for i in 0..(input.len() - 1) {
-            println!("ch1: {} ch2: {}", input[i] as char, input[i + 1] as char);
             len += find_shortest(
                 input[i],
                 input[i + 1],
@@ -1,8 +1,7 @@
 use std::collections::HashMap;
-use std::fs;
-use std::io;
 
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Example, Solution};
 
 #[derive(Debug)]
 struct SecretNumber {
@@ -93,12 +92,37 @@ fn part2(input: &str) -> i64 {
     buyers_map.into_values().max().unwrap()
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2024/day22.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u32 = 2024;
+    const DAY: usize = 22;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            file: "day22_example.txt",
+            part1: Some("37327623"),
+            part2: None,
+        },
+        Example {
+            file: "day22_example2.txt",
+            part1: None,
+            part2: Some("23"),
+        },
+    ];
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
 
 #[cfg(test)]
@@ -135,7 +159,7 @@ mod tests {
 
     #[test]
     fn day22_example2() {
-        let mut nums = vec![
+        let mut nums = [
             SecretNumber::new(1),
             SecretNumber::new(2),
             SecretNumber::new(3),
@@ -1,10 +1,9 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::fs;
-use std::io;
 
+use aocutils::error::AocError;
 use aocutils::graph::*;
-use aocutils::timeln;
+use aocutils::solution::{Answer, Solution};
 
 fn part1(input: &str) -> usize {
     let mut network = Graph::new();
@@ -75,10 +74,23 @@ fn part2(input: &str) -> String {
     names.join(",").to_string()
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2024/day23.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day23;
 
-    Ok(())
+impl Solution for Day23 {
+    const YEAR: u32 = 2024;
+    const DAY: usize = 23;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
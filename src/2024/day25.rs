@@ -1,7 +1,5 @@
-use std::fs;
-use std::io;
-
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Example, Solution};
 
 fn is_lock(input: &[Vec<char>]) -> bool {
     input[0].iter().all(|c| *c == '#')
@@ -65,10 +63,24 @@ fn part1(input: &str) -> usize {
         .sum::<usize>()
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2024/day25.txt")?;
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u32 = 2024;
+    const DAY: usize = 25;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day25_example.txt",
+        part1: Some("3"),
+        part2: None,
+    }];
 
-    timeln!("part1: {}", part1(&input));
+    type Input<'a> = &'a str;
 
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
 }
@@ -1,9 +1,8 @@
-use std::fs;
-use std::io;
-
-use aocutils::timeln;
 use regex::Regex;
 
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Example, Solution};
+
 fn part1(input: &str) -> i64 {
     let pattern = Regex::new(r"mul\(([0-9]+),([0-9]+)\)").unwrap();
     let mut ret = 0;
@@ -43,10 +42,35 @@ fn part2(input: &str) -> i64 {
     ret
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2024/day3.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u32 = 2024;
+    const DAY: usize = 3;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            file: "day3_example.txt",
+            part1: Some("161"),
+            part2: None,
+        },
+        Example {
+            file: "day3_example2.txt",
+            part1: None,
+            part2: Some("48"),
+        },
+    ];
 
-    Ok(())
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
@@ -1,11 +1,9 @@
-use std::fs;
-use std::io;
-
 use aocutils::coord::Coord;
-use aocutils::direction::Direction;
 use aocutils::direction::DIRECTIONS;
+use aocutils::direction::Direction;
+use aocutils::error::AocError;
 use aocutils::grid::in_bounds;
-use aocutils::timeln;
+use aocutils::solution::{Answer, Solution};
 
 fn search(grid: &[Vec<char>], mut coord: Coord, xdir: i64, ydir: i64, needle: &str) -> u64 {
     for ch in needle.chars() {
@@ -106,10 +104,23 @@ fn part2(input: &str) -> u64 {
     cnt
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2024/day4.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u32 = 2024;
+    const DAY: usize = 4;
+
+    type Input<'a> = &'a str;
 
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
@@ -1,8 +1,7 @@
 use std::collections::HashMap;
-use std::fs;
-use std::io;
 
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
 
 fn validate_pages(rules: &HashMap<usize, Vec<usize>>, pages: &Vec<usize>) -> usize {
     let mut printed = [false; 100];
@@ -100,10 +99,23 @@ fn part2(input: &str) -> usize {
     pages.iter().map(|p| fix_pages(&rules, p)).sum::<usize>()
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2024/day5.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day5;
 
-    Ok(())
+impl Solution for Day5 {
+    const YEAR: u32 = 2024;
+    const DAY: usize = 5;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
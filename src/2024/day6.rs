@@ -1,9 +1,7 @@
-use std::fs;
-use std::io;
-
 use aocutils::coord::Coord;
+use aocutils::error::AocError;
 use aocutils::grid::{direction::GridDirection, in_bounds};
-use aocutils::timeln;
+use aocutils::solution::{Answer, Example, Solution};
 
 fn dir_to_usize(dir: GridDirection) -> usize {
     use GridDirection::*;
@@ -154,10 +152,28 @@ fn part2(input: &str) -> i32 {
     cnt
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2024/day6.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u32 = 2024;
+    const DAY: usize = 6;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day6_example.txt",
+        part1: Some("41"),
+        part2: Some("6"),
+    }];
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
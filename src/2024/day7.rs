@@ -1,7 +1,5 @@
-use std::fs;
-use std::io;
-
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Example, Solution};
 
 enum Ops {
     Add,
@@ -142,10 +140,28 @@ fn part2(input: &str) -> usize {
         .sum::<usize>()
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2024/day7.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u32 = 2024;
+    const DAY: usize = 7;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day7_example.txt",
+        part1: Some("3749"),
+        part2: Some("11387"),
+    }];
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
@@ -1,9 +1,8 @@
 use std::collections::HashSet;
-use std::fs;
-use std::io;
 
 use aocutils::coord::Coord;
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Example, Solution};
 
 struct Antenna {
     pos: Coord,
@@ -108,10 +107,28 @@ fn part2(input: &str) -> usize {
     antinodes.len()
 }
 
-pub fn run(_benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2024/day8.txt")?;
-    timeln!("part1: {}", part1(&input));
-    timeln!("part2: {}", part2(&input));
+pub struct Day8;
 
-    Ok(())
+impl Solution for Day8 {
+    const YEAR: u32 = 2024;
+    const DAY: usize = 8;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day8_example.txt",
+        part1: Some("14"),
+        part2: Some("34"),
+    }];
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
 }
@@ -1,7 +1,5 @@
-use std::fs;
-use std::io;
-
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
 
 struct Dial {
     pos: i32,
@@ -65,24 +63,23 @@ fn part2(input: &str) -> u32 {
         .sum::<u32>()
 }
 
-pub fn run(benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2025/day1.txt");
+pub struct Day1;
 
-    if let Err(e) = input {
-        if e.kind() == io::ErrorKind::NotFound {
-            println!("Input file not found!");
-        }
+impl Solution for Day1 {
+    const YEAR: u32 = 2025;
+    const DAY: usize = 1;
 
-        Err(e)
-    } else {
-        if benchmark {
-            timeln!("part1: {}", part1(input.as_ref().unwrap()));
-            timeln!("part2: {}", part2(input.as_ref().unwrap()));
-        } else {
-            println!("part1: {}", part1(input.as_ref().unwrap()));
-            println!("part2: {}", part2(input.as_ref().unwrap()));
-        }
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
 
-        Ok(())
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
     }
 }
@@ -4,10 +4,10 @@ use lp_modeler::dsl::*;
 use lp_modeler::solvers::{CbcSolver, SolverTrait};
 
 use std::collections::{HashMap, VecDeque};
-use std::fs;
-use std::io;
 
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
+
 
 fn min_presses(light: u64, buttons: &[u64]) -> usize {
     let mut queue: VecDeque<(usize, u64, u64)> = VecDeque::new();
@@ -67,7 +67,7 @@ fn part1(input: &str) -> usize {
         .sum::<usize>()
 }
 
-fn part2(input: &str) -> usize {
+fn part2(input: &str) -> Result<usize, AocError> {
     let mut presses = 0;
 
     for parts in input
@@ -111,38 +111,32 @@ fn part2(input: &str) -> usize {
         // crate expects the binary "cbc", but the fedora package provides a binary
         // called "Cbc".
         let solver = CbcSolver::new().command_name(String::from("Cbc"));
-        match solver.run(&problem) {
-            Ok(solution) => {
-                presses += solution.results.values().sum::<f32>() as usize;
-            }
-            Err(msg) => {
-                println!("{}", msg);
-                return 0;
-            }
-        }
+        let solution = solver
+            .run(&problem)
+            .map_err(|e| AocError::unsolvable(format!("the solver failed: {}", e)))?;
+        presses += solution.results.values().sum::<f32>() as usize;
     }
 
-    presses
+    Ok(presses)
 }
 
-pub fn run(benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2025/day10.txt");
+pub struct Day10;
 
-    if let Err(e) = input {
-        if e.kind() == io::ErrorKind::NotFound {
-            println!("Input file not found!");
-        }
+impl Solution for Day10 {
+    const YEAR: u32 = 2025;
+    const DAY: usize = 10;
 
-        Err(e)
-    } else {
-        if benchmark {
-            timeln!("part1: {}", part1(input.as_ref().unwrap()));
-            timeln!("part2: {}", part2(input.as_ref().unwrap()));
-        } else {
-            println!("part1: {}", part1(input.as_ref().unwrap()));
-            println!("part2: {}", part2(input.as_ref().unwrap()));
-        }
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
 
-        Ok(())
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input)?.into())
     }
 }
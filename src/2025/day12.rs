@@ -1,7 +1,5 @@
-use std::fs;
-use std::io;
-
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
 
 fn part1(input: &str) -> usize {
     let mut grids = Vec::new();
@@ -37,22 +35,19 @@ fn part1(input: &str) -> usize {
         .count()
 }
 
-pub fn run(benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2025/day12.txt");
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u32 = 2025;
+    const DAY: usize = 12;
 
-    if let Err(e) = input {
-        if e.kind() == io::ErrorKind::NotFound {
-            println!("Input file not found!");
-        }
+    type Input<'a> = &'a str;
 
-        Err(e)
-    } else {
-        if benchmark {
-            timeln!("part1: {}", part1(input.as_ref().unwrap()));
-        } else {
-            println!("part1: {}", part1(input.as_ref().unwrap()));
-        }
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
 
-        Ok(())
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
     }
 }
@@ -1,7 +1,5 @@
-use std::fs;
-use std::io;
-
-use aocutils::timeln;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Solution};
 
 fn bank_max(bank: &str, digits: usize) -> usize {
     let mut mdigs: Vec<char> = vec!['0'; digits];
@@ -31,24 +29,23 @@ fn part2(input: &str) -> usize {
     input.lines().map(|s| bank_max(s, 12)).sum()
 }
 
-pub fn run(benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2025/day3.txt");
+pub struct Day3;
 
-    if let Err(e) = input {
-        if e.kind() == io::ErrorKind::NotFound {
-            println!("Input file not found!");
-        }
+impl Solution for Day3 {
+    const YEAR: u32 = 2025;
+    const DAY: usize = 3;
 
-        Err(e)
-    } else {
-        if benchmark {
-            timeln!("part1: {}", part1(input.as_ref().unwrap()));
-            timeln!("part2: {}", part2(input.as_ref().unwrap()));
-        } else {
-            println!("part1: {}", part1(input.as_ref().unwrap()));
-            println!("part2: {}", part2(input.as_ref().unwrap()));
-        }
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
 
-        Ok(())
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
     }
 }
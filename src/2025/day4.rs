@@ -1,58 +1,36 @@
-use std::fs;
-use std::io;
+use aocutils::error::AocError;
+use aocutils::grid::{Connectivity, Grid};
+use aocutils::solution::{Answer, Example, Solution};
 
-use aocutils::coord::Coord;
-use aocutils::direction;
-use aocutils::grid::in_bounds;
-use aocutils::timeln;
-
-fn count_movable(grid: &mut [Vec<char>], remove: bool) -> usize {
+fn count_movable(grid: &mut Grid<char>, remove: bool) -> usize {
     let mut total = Vec::new();
 
-    for i in 0..grid.len() {
-        for j in (0..grid.len()).filter(|j| grid[i][*j] == '@') {
-            let mut dirs = direction::DIRECTIONS.into_iter();
-            let mut rolls = 0;
-
-            while let Some(dir) = dirs.next()
-                && rolls < 4
-            {
-                let c = Coord::new(i as i64, j as i64) + dir.into();
-
-                if in_bounds(grid, c) && grid[c.x as usize][c.y as usize] == '@' {
-                    rolls += 1;
-                }
-            }
+    for c in grid.positions(&'@') {
+        let rolls = grid
+            .neighbors(c, Connectivity::Eight)
+            .filter(|(_, cell)| **cell == '@')
+            .count();
 
-            if rolls < 4 {
-                total.push((i, j));
-            }
+        if rolls < 4 {
+            total.push(c);
         }
     }
 
     if remove {
-        for (i, j) in &total {
-            grid[*i][*j] = '.';
+        for c in &total {
+            grid[*c] = '.';
         }
     }
 
     total.len()
 }
 
-fn part1(input: &str) -> usize {
-    let mut grid = input
-        .lines()
-        .map(|s| s.chars().collect())
-        .collect::<Vec<Vec<char>>>();
-
-    count_movable(&mut grid, false)
+fn part1(grid: &Grid<char>) -> usize {
+    count_movable(&mut grid.clone(), false)
 }
 
-fn part2(input: &str) -> usize {
-    let mut grid = input
-        .lines()
-        .map(|s| s.chars().collect())
-        .collect::<Vec<Vec<char>>>();
+fn part2(grid: &Grid<char>) -> usize {
+    let mut grid = grid.clone();
     let mut cnt = usize::MAX;
     let mut total = 0;
 
@@ -64,24 +42,28 @@ fn part2(input: &str) -> usize {
     total
 }
 
-pub fn run(benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/2025/day4.txt");
+pub struct Day4;
 
-    if let Err(e) = input {
-        if e.kind() == io::ErrorKind::NotFound {
-            println!("Input file not found!");
-        }
+impl Solution for Day4 {
+    const YEAR: u32 = 2025;
+    const DAY: usize = 4;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "day4_example.txt",
+        part1: Some("14"),
+        part2: Some("44"),
+    }];
 
-        Err(e)
-    } else {
-        if benchmark {
-            timeln!("part1: {}", part1(input.as_ref().unwrap()));
-            timeln!("part2: {}", part2(input.as_ref().unwrap()));
-        } else {
-            println!("part1: {}", part1(input.as_ref().unwrap()));
-            println!("part2: {}", part2(input.as_ref().unwrap()));
-        }
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Grid::parse(input, |c| c)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
 
-        Ok(())
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
     }
 }
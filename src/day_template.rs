@@ -1,34 +1,49 @@
-use std::io;
-use std::fs;
+use aocutils::error::AocError;
+use aocutils::solution::{Answer, Example, Solution};
 
-use aocutils::timeln;
-
-fn part1(input: &str) -> i32 {
+fn part1(_input: &str) -> i32 {
     0
 }
 
-fn part2(input: &str) -> i32 {
+fn part2(_input: &str) -> i32 {
     0
 }
 
-pub fn run(benchmark: bool) -> io::Result<()> {
-    let input = fs::read_to_string("inputs/20XX/dayYY_example.txt");
-
-    if let Err(e) = input {
-        if e.kind() == io::ErrorKind::NotFound {
-            println!("Input file not found!");
-        }
-
-        Err(e)
-    } else {
-        if benchmark {
-            timeln!("part1: {}", part1(input.as_ref().unwrap()));
-            timeln!("part2: {}", part2(input.as_ref().unwrap()));
-        } else {
-            println!("part1: {}", part1(input.as_ref().unwrap()));
-            println!("part2: {}", part2(input.as_ref().unwrap()));
-        }
-
-        Ok(())
+pub struct DayYY;
+
+impl Solution for DayYY {
+    const YEAR: u32 = 20XX;
+    const DAY: usize = YY;
+    const EXAMPLES: &'static [Example] = &[Example {
+        file: "dayYY_example.txt",
+        part1: None,
+        part2: None,
+    }];
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = include_str!("../../inputs/20XX/dayYY_example.txt");
+
+        assert_eq!(part1(input), 0);
+        assert_eq!(part2(input), 0);
     }
 }
@@ -1,10 +1,40 @@
+#[path = "utils/alloc.rs"]
+pub mod alloc;
+#[path = "utils/answers.rs"]
+pub mod answers;
+#[path = "utils/bench.rs"]
+pub mod bench;
+#[path = "utils/client.rs"]
+pub mod client;
 #[path = "utils/coord.rs"]
 pub mod coord;
-#[path = "utils/grid/mod.rs"]
-pub mod grid;
-#[path = "utils/graph.rs"]
-pub mod graph;
+#[path = "utils/description.rs"]
+pub mod description;
 #[path = "utils/direction.rs"]
 pub mod direction;
-#[path = "utils/timing.rs"]
-pub mod timing;
+#[path = "utils/error.rs"]
+pub mod error;
+#[path = "utils/graph.rs"]
+pub mod graph;
+#[path = "utils/grid/mod.rs"]
+pub mod grid;
+#[path = "utils/history.rs"]
+pub mod history;
+#[path = "utils/input.rs"]
+pub mod input;
+#[path = "utils/leaderboard.rs"]
+pub mod leaderboard;
+#[path = "utils/parse.rs"]
+pub mod parse;
+#[path = "utils/report.rs"]
+pub mod report;
+#[path = "utils/runner.rs"]
+pub mod runner;
+#[path = "utils/scaffold.rs"]
+pub mod scaffold;
+#[path = "utils/session.rs"]
+pub mod session;
+#[path = "utils/solution.rs"]
+pub mod solution;
+#[path = "utils/status.rs"]
+pub mod status;
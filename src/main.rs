@@ -1,21 +1,33 @@
-use clap::{Parser, Subcommand};
-use reqwest::Client;
-use reqwest::header::{COOKIE, HeaderValue};
-use std::fs::{self, File};
-use std::io::Write;
-use std::io::{self, Read};
+use aocutils::alloc::{self, CountingAlloc};
+use aocutils::answers::{ANSWERS_DIR, Verdict, YearAnswers};
+use aocutils::bench::{self, BenchResult};
+use aocutils::client::{AocClient, Submission};
+use aocutils::description;
+use aocutils::error::AocError;
+use aocutils::history::{self, Comparison, HISTORY_FILE, History, Record, Revision};
+use aocutils::input::{self, Inputs, Source};
+use aocutils::leaderboard::{self, Leaderboard};
+use aocutils::report::{self, DayReport, Report};
+use aocutils::runner::{self, RunOptions};
+use aocutils::scaffold;
+use aocutils::session;
+use aocutils::solution::{Answer, Puzzle, Registry, RunResult};
+use aocutils::status::{self, PartStatus};
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use std::fmt::Display;
+use std::fs;
+use std::io;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-#[path = "2022/mod.rs"]
-mod year22;
-#[path = "2023/mod.rs"]
-mod year23;
-#[path = "2024/mod.rs"]
-mod year24;
-#[path = "2025/mod.rs"]
-mod year25;
+mod watch;
 
-const YEARS: [&str; 4] = ["2022", "2023", "2024", "2025"];
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+// year modules, YEARS and registry(), generated by build.rs from src/<year>/day<day>.rs
+include!(concat!(env!("OUT_DIR"), "/years.rs"));
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
@@ -28,33 +40,190 @@ struct Args {
 enum Commands {
     // Run the solution for <year> <day>. If <day> isn't specified, run all solutions for <year>
     Run {
-        // year to run, default to 2024
+        // year to run, defaults to the latest year with solutions
         #[arg(short, long, default_value_t = String::from(*YEARS.last().unwrap()))]
         year: String,
         // day to run
         #[arg(short, long)]
         day: Option<usize>,
+        // run every year instead of just <year>
+        #[arg(long, conflicts_with_all = ["year", "day"])]
+        all_years: bool,
+        // number of days to run at the same time
+        #[arg(short, long, default_value_t = 1)]
+        jobs: usize,
+        // give up on a day after this many seconds
+        #[arg(long, value_parser = parse_timeout)]
+        timeout: Option<Duration>,
+        // read the input from this file instead, or from stdin if it's "-"
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
         // time the solution
         #[arg(long, action)]
         nobenchmark: bool,
+        // report the peak heap use, number of allocations and bytes allocated of each part
+        #[arg(long)]
+        profile_mem: bool,
+        // how to print the answers
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+    },
+    // Benchmark the solutions for <year> <day>. Each solution is run <warmup> times before
+    // being timed over <iterations> runs
+    Bench {
+        #[arg(short, long, default_value_t = String::from(*YEARS.last().unwrap()))]
+        year: String,
+        #[arg(short, long)]
+        day: Option<usize>,
+        #[arg(short, long, default_value_t = 10)]
+        iterations: usize,
+        #[arg(long, default_value_t = 1)]
+        warmup: usize,
+        // Compare against the timings recorded for this git revision
+        #[arg(long)]
+        compare: Option<String>,
+        // How much slower (in percent) a step has to get to be flagged by --compare
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+    },
+    // Run the solutions for <year> <day> and check them against the answers recorded in
+    // answers/<year>.toml. If <year> isn't specified, verify every year
+    Verify {
+        #[arg(short, long)]
+        year: Option<String>,
+        #[arg(short, long)]
+        day: Option<usize>,
+    },
+    // Submit <answer> for <part> of <year> <day>. If <answer> isn't given, the solution is
+    // run and its answer is submitted. Correct answers are recorded in answers/<year>.toml
+    Submit {
+        #[arg(short, long, default_value_t = String::from(*YEARS.last().unwrap()))]
+        year: String,
+        #[arg(short, long)]
+        day: usize,
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        answer: Option<String>,
+        // Session key file. Defaults to $AOC_SESSION, then ~/.config/aoc/session
+        #[arg(short, long)]
+        session: Option<PathBuf>,
+    },
+    // Download the description of <year> <day> to puzzles/<year>/day<day>.md, and save its
+    // first example to inputs/<year>/day<day>_example.txt if there isn't one yet
+    Fetch {
+        #[arg(short, long, default_value_t = String::from(*YEARS.last().unwrap()))]
+        year: String,
+        #[arg(short, long)]
+        day: usize,
+        // Session key file. Defaults to $AOC_SESSION, then ~/.config/aoc/session
+        #[arg(short, long)]
+        session: Option<PathBuf>,
     },
     // Set up a template file for <year> <day>. Also puts the puzzle input in
-    // inputs/<year>/day<day>.txt
+    // inputs/<year>/day<day>.txt if there's a session key
     Create {
         #[arg(short, long, default_value_t = String::from(*YEARS.last().unwrap()))]
         year: String,
         #[arg(short, long)]
         day: Option<usize>,
-        // Session key for getting input. If not specified, this command will only create a template
-        // file
+        // Session key file. Defaults to $AOC_SESSION, then ~/.config/aoc/session
         #[arg(short, long)]
         session: Option<PathBuf>,
         // Only get inputs for given year (day is optional)
         #[arg(long)]
         download: bool,
+        // Download inputs again, even if they already exist
+        #[arg(long)]
+        force: bool,
+    },
+    // Re-run <year> <day> whenever its source, aocutils or its input changes, showing how
+    // the answers changed since the last run
+    Watch {
+        #[arg(short, long, default_value_t = String::from(*YEARS.last().unwrap()))]
+        year: String,
+        #[arg(short, long)]
+        day: usize,
+        // run on the day's first example instead of its input
+        #[arg(long)]
+        example: bool,
+        // build in release mode
+        #[arg(long)]
+        release: bool,
+    },
+    // Write a report of every day of <year> to <out>, with answers, timings, a runtime chart
+    // and the aocutils modules each day uses. The report is Markdown if <out> ends in .md,
+    // and HTML otherwise
+    Report {
+        #[arg(short, long, default_value_t = String::from(*YEARS.last().unwrap()))]
+        year: String,
+        #[arg(short, long, default_value = "report.html")]
+        out: PathBuf,
+        // hide the answers
+        #[arg(long)]
+        redact: bool,
+        // use the results of the last run of each day instead of running them
+        #[arg(long)]
+        from_history: bool,
+        // give up on a day after this many seconds
+        #[arg(long, value_parser = parse_timeout)]
+        timeout: Option<Duration>,
+    },
+    // Show which days have solutions, inputs and answers, and how long they took to run
+    Status {
+        // only show this year
+        #[arg(short, long)]
+        year: Option<String>,
+    },
+    // Set up the directory for a new year, with a template for its first day
+    NewYear {
+        year: u32,
+    },
+    // Show the standings of private leaderboard <id>. Leaderboards are cached for
+    // 15 minutes, as the site asks
+    Leaderboard {
+        id: u64,
+        #[arg(short, long, default_value_t = YEARS.last().unwrap().parse().unwrap())]
+        year: u32,
+        // Session key file. Defaults to $AOC_SESSION, then ~/.config/aoc/session
+        #[arg(short, long)]
+        session: Option<PathBuf>,
+    },
+    // Manage the session key used to talk to the Advent of Code website
+    Session {
+        #[command(subcommand)]
+        command: SessionCommand,
     },
 }
 
+#[derive(Subcommand, Debug)]
+enum SessionCommand {
+    // Check that the session key is valid and print who it belongs to
+    Check {
+        // Session key file. Defaults to $AOC_SESSION, then ~/.config/aoc/session
+        #[arg(short, long)]
+        session: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Format {
+    Table,
+    Json,
+}
+
+/// Parses a --timeout of @arg seconds, which has to be more than 0
+fn parse_timeout(arg: &str) -> Result<Duration, String> {
+    let secs: f64 = arg.parse().map_err(|e| format!("{}", e))?;
+
+    if secs.is_nan() || secs <= 0.0 {
+        return Err(String::from("the timeout has to be more than 0 seconds"));
+    }
+
+    Duration::try_from_secs_f64(secs).map_err(|e| e.to_string())
+}
+
 fn get_year(arg: &str) -> Option<&str> {
     let years: Vec<&str> = YEARS
         .iter()
@@ -76,10 +245,93 @@ async fn main() -> io::Result<()> {
         Some(Commands::Run {
             year,
             day,
+            all_years,
+            jobs,
+            timeout,
+            input,
             nobenchmark,
+            profile_mem,
+            format,
+        }) => {
+            // allocations are counted for the whole process, so days running
+            // at the same time would be counted together
+            if *profile_mem {
+                if *jobs > 1 {
+                    return Err(io::Error::other("--profile-mem can't be used with --jobs"));
+                }
+
+                alloc::enable();
+            }
+
+            let opts = RunOptions {
+                jobs: *jobs,
+                timeout: *timeout,
+            };
+
+            let source = Source::from_arg(input.as_deref());
+
+            if *all_years {
+                run(&YEARS, None, source, !*nobenchmark, *format, opts)
+            } else if let Some(y) = get_year(year) {
+                run(&[y], *day, source, !*nobenchmark, *format, opts)
+            } else {
+                Err(io::Error::other(format!("Invalid year: {}", year)))
+            }
+        }
+        Some(Commands::Bench {
+            year,
+            day,
+            iterations,
+            warmup,
+            compare,
+            threshold,
+            format,
         }) => {
             if let Some(y) = get_year(year) {
-                run(y, *day, !*nobenchmark)
+                let opts = BenchOptions {
+                    warmup: *warmup,
+                    iterations: *iterations,
+                    compare: compare.clone(),
+                    threshold: *threshold / 100.0,
+                    format: *format,
+                };
+
+                bench(y, *day, opts)
+            } else {
+                Err(io::Error::other(format!("Invalid year: {}", year)))
+            }
+        }
+        Some(Commands::Verify { year, day }) => {
+            if let Some(year) = year {
+                if let Some(y) = get_year(year) {
+                    verify(&[y], *day)
+                } else {
+                    Err(io::Error::other(format!("Invalid year: {}", year)))
+                }
+            } else {
+                verify(&YEARS, *day)
+            }
+        }
+        Some(Commands::Submit {
+            year,
+            day,
+            part,
+            answer,
+            session,
+        }) => {
+            if let Some(y) = get_year(year) {
+                let client = AocClient::new(&session::resolve(session.as_deref())?);
+
+                submit(&client, y, *day, *part as usize, answer.as_deref()).await
+            } else {
+                Err(io::Error::other(format!("Invalid year: {}", year)))
+            }
+        }
+        Some(Commands::Fetch { year, day, session }) => {
+            if let Some(y) = get_year(year) {
+                let client = AocClient::new(&session::resolve(session.as_deref())?);
+
+                fetch(&client, y, *day).await
             } else {
                 Err(io::Error::other(format!("Invalid year: {}", year)))
             }
@@ -89,13 +341,83 @@ async fn main() -> io::Result<()> {
             day,
             session,
             download,
+            force,
         }) => {
             if let Some(y) = get_year(year) {
-                create(y, *day, session.clone(), *download).await
+                create(y, *day, session.as_deref(), *download, *force).await
             } else {
                 Err(io::Error::other(format!("Invalid year: {}", year)))
             }
         }
+        Some(Commands::Watch {
+            year,
+            day,
+            example,
+            release,
+        }) => {
+            if let Some(y) = get_year(year) {
+                let registry = registry();
+                let year = y.parse::<u32>().map_err(io::Error::other)?;
+                let puzzle = registry
+                    .get(year, *day)
+                    .ok_or_else(|| io::Error::other(format!("Invalid day: {}", day)))?;
+                let inputs = Inputs::locate();
+
+                let input = match (example, puzzle.examples.first()) {
+                    (false, _) => inputs.path(puzzle),
+                    (true, Some(e)) => inputs.example_path(puzzle, e),
+                    (true, None) => inputs
+                        .year_dir(year)
+                        .join(format!("day{}_example.txt", day)),
+                };
+
+                watch::watch(puzzle, &input, *release)
+            } else {
+                Err(io::Error::other(format!("Invalid year: {}", year)))
+            }
+        }
+        Some(Commands::Report {
+            year,
+            out,
+            redact,
+            from_history,
+            timeout,
+        }) => {
+            if let Some(y) = get_year(year) {
+                report(y.parse().unwrap(), out, *redact, *from_history, *timeout)
+            } else {
+                Err(io::Error::other(format!("Invalid year: {}", year)))
+            }
+        }
+        Some(Commands::Status { year }) => match year {
+            Some(year) => match get_year(year) {
+                Some(y) => status(&[y]),
+                None => Err(io::Error::other(format!("Invalid year: {}", year))),
+            },
+            None => status(&YEARS),
+        },
+        Some(Commands::NewYear { year }) => new_year(*year),
+        Some(Commands::Leaderboard { id, year, session }) => {
+            let client = AocClient::new(&session::resolve(session.as_deref())?);
+            let dir = input::project_root().join(leaderboard::CACHE_DIR);
+            let (board, age) = leaderboard::load(&client, &dir, *year, *id).await?;
+
+            if !age.is_zero() {
+                println!("(cached {} minutes ago)\n", age.as_secs() / 60);
+            }
+
+            print_leaderboard(&board, *year);
+            Ok(())
+        }
+        Some(Commands::Session {
+            command: SessionCommand::Check { session },
+        }) => {
+            let client = AocClient::new(&session::resolve(session.as_deref())?);
+            let user = client.user().await?;
+
+            println!("Session is valid, logged in as {}", user);
+            Ok(())
+        }
         _ => Ok(()),
     }
 }
@@ -103,11 +425,10 @@ async fn main() -> io::Result<()> {
 async fn create(
     year: &str,
     day: Option<usize>,
-    session: Option<PathBuf>,
+    session: Option<&Path>,
     download: bool,
+    force: bool,
 ) -> io::Result<()> {
-    let client = create_client();
-
     let lower = day.unwrap_or(1);
     let upper = day.unwrap_or(25);
 
@@ -115,93 +436,892 @@ async fn create(
         for i in lower..=upper {
             create_day(year, i)?;
         }
-    } else if session.is_none() {
-        return Err(io::Error::other(
-            "Session cookie file (-s option) required for --download option",
-        ));
     }
 
-    if let Some(file) = session {
-        let key = fs::read_to_string(file).expect("Could not find session cookie file");
-        for i in lower..=upper {
-            download_inputs(&client, &key, year, i).await?;
+    // inputs are only required with --download, otherwise they're
+    // just downloaded if there's a session key to do it with
+    let key = match session::resolve(session) {
+        Ok(key) => key,
+        Err(e) if !download && e.kind() == io::ErrorKind::NotFound => {
+            println!("{}, so inputs weren't downloaded", e);
+            return Ok(());
         }
+        Err(e) => return Err(e),
+    };
+
+    let client = AocClient::new(&key);
+    for i in lower..=upper {
+        download_inputs(&client, year, i, force).await?;
     }
 
     Ok(())
 }
 
+/// Creates src/<year>/day<day>.rs from the day template, along with an empty
+/// example input. The build picks up the new day by itself
 fn create_day(year: &str, day: usize) -> io::Result<()> {
-    let path = format!("src/{}/day{}.rs", year, day);
+    let root = input::project_root();
+    let dir = root.join("src").join(year);
+    let path = dir.join(format!("day{}.rs", day));
 
-    if Path::new(&path).exists() {
+    if path.exists() {
         return Ok(());
     }
 
-    let mut template = File::open("src/day_template.rs").expect("Could not find day template");
-    let mut day_file = File::create(path).expect("Error creating day file");
-    let mut buf = Vec::new();
+    if !dir.is_dir() {
+        return Err(io::Error::other(format!(
+            "{} doesn't exist, create it with \"aoc new-year {}\"",
+            dir.display(),
+            year
+        )));
+    }
+
+    let year = year.parse::<u32>().map_err(io::Error::other)?;
+    let template = input::read_file(&root.join("src").join("day_template.rs"))?;
+
+    // the template's test includes the example, so it has to exist
+    let example = root
+        .join("inputs")
+        .join(year.to_string())
+        .join(format!("day{}_example.txt", day));
+    if !example.exists() {
+        input::write_atomic(&example, "")?;
+    }
+
+    input::write_atomic(&path, &scaffold::render_template(&template, year, day))?;
 
-    template.read_to_end(&mut buf)?;
-    day_file.write_all(&buf)
+    println!("Created {}", path.display());
+    Ok(())
 }
 
-async fn download_inputs(client: &Client, key: &str, year: &str, day: usize) -> io::Result<()> {
-    let year_path = format!("inputs/{}", year);
+/// Sets up src/<year> with a template for its first day
+fn new_year(year: u32) -> io::Result<()> {
+    if year < 2015 {
+        return Err(io::Error::other("Advent of Code started in 2015"));
+    }
 
-    if !Path::new(&year_path).exists() {
-        fs::create_dir(year_path)?;
+    let dir = input::project_root().join("src").join(year.to_string());
+    if dir.exists() {
+        return Err(io::Error::other(format!(
+            "{} already exists",
+            dir.display()
+        )));
     }
 
-    let day_path = format!("inputs/{}/day{}.txt", year, day);
-    if Path::new(&day_path).exists() {
+    fs::create_dir_all(&dir)?;
+    fs::create_dir_all(Inputs::locate().year_dir(year))?;
+    create_day(&year.to_string(), 1)?;
+
+    println!("Set up {}, it'll be available after the next build", year);
+    Ok(())
+}
+
+/// Downloads the input for @year @day, unless it's already been downloaded
+/// and @force isn't set. Empty inputs are left over from failed downloads by
+/// older versions, so they're downloaded again
+async fn download_inputs(
+    client: &AocClient,
+    year: &str,
+    day: usize,
+    force: bool,
+) -> io::Result<()> {
+    let year = year.parse::<u32>().map_err(io::Error::other)?;
+    let path = Inputs::locate()
+        .year_dir(year)
+        .join(format!("day{}.txt", day));
+
+    if !force && fs::metadata(&path).is_ok_and(|m| m.len() > 0) {
         return Ok(());
     }
 
-    let mut input = File::create(day_path)?;
-    let url = format!("https://adventofcode.com/{}/day/{}/input", year, day);
+    let text = client.input(year, day).await?;
+    input::write_atomic(&path, &text)?;
+    println!("Downloaded {}", path.display());
+
+    Ok(())
+}
+
+/// Gets the Puzzle for @year @day, or every Puzzle in @year if @day is None
+fn select(registry: &Registry, year: u32, day: Option<usize>) -> Vec<&Puzzle> {
+    if let Some(n) = day {
+        if let Some(puzzle) = registry.get(year, n) {
+            vec![puzzle]
+        } else {
+            println!("Invalid day: {}", n);
+            Vec::new()
+        }
+    } else {
+        registry.year(year).iter().collect()
+    }
+}
+
+/// Runs @day of each of @years (or every day if @day is None) on the input from
+/// @source. Results are printed in order, even when several days are run at the
+/// same time
+fn run(
+    years: &[&str],
+    day: Option<usize>,
+    source: Source,
+    benchmark: bool,
+    format: Format,
+    opts: RunOptions,
+) -> io::Result<()> {
+    let registry = registry();
+    let mut puzzles = Vec::new();
+
+    for year in years {
+        let year = year.parse::<u32>().map_err(io::Error::other)?;
+        puzzles.extend(select(&registry, year, day).into_iter().copied());
+    }
+
+    let inputs = Inputs::locate();
+    let record = source == Source::Default && opts.jobs <= 1 && !alloc::is_enabled();
+    let rx = runner::run_all(puzzles.clone(), opts, move |puzzle| {
+        source.read(&inputs, puzzle)
+    });
+
+    // results arrive in whatever order they finish in, so hold on to them
+    // until every earlier day has been printed
+    let mut results: Vec<Option<Result<RunResult, AocError>>> =
+        puzzles.iter().map(|_| None).collect();
+    let mut printed = 0;
+
+    if matches!(format, Format::Table) && years.len() == 1 {
+        print_header(benchmark);
+    }
+
+    for (i, result) in rx {
+        results[i] = Some(result);
+
+        if let Format::Table = format {
+            while let Some(Some(result)) = results.get(printed) {
+                let puzzle = &puzzles[printed];
+
+                if years.len() > 1 && (printed == 0 || puzzles[printed - 1].year != puzzle.year) {
+                    println!("{}", puzzle.year);
+                    print_header(benchmark);
+                }
+
+                match result {
+                    Ok(result) => print_result(result, benchmark),
+                    Err(e) => println!("{:>4}  {:>4}  Error! ({})", puzzle.day, "", e),
+                }
+
+                printed += 1;
+            }
+        }
+    }
+
+    if let Format::Json = format {
+        let results: Vec<JsonResult<&RunResult>> = puzzles
+            .iter()
+            .zip(&results)
+            .map(|(puzzle, result)| match result {
+                Some(result) => JsonResult::new(puzzle, result.as_ref()),
+                None => JsonResult::new(puzzle, Err("not run")),
+            })
+            .collect();
+
+        println!("{}", serde_json::to_string_pretty(&results)?);
+    }
+
+    // timings of days that were run alongside others, on other inputs or while
+    // counting allocations aren't comparable to the rest of the history, so they
+    // aren't recorded
+    if record {
+        let rev = Revision::current();
+        let records: Vec<Record> = results
+            .iter()
+            .flatten()
+            .flatten()
+            .flat_map(|r| Record::from_run(&rev, r))
+            .collect();
+
+        save_timings(&records);
+    }
+
+    Ok(())
+}
+
+/// Appends @records to the timing history. Failing to save them isn't worth
+/// failing the command over, so errors are only reported
+fn save_timings(records: &[Record]) {
+    if let Err(e) = History::new(&input::project_root().join(HISTORY_FILE)).append(records) {
+        eprintln!("Could not save timings to {}: {}", HISTORY_FILE, e);
+    }
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum JsonResult<T> {
+    Ok(T),
+    Err {
+        year: u32,
+        day: usize,
+        error: String,
+    },
+}
+
+impl<T> JsonResult<T> {
+    fn new<E: Display>(puzzle: &Puzzle, result: Result<T, E>) -> Self {
+        match result {
+            Ok(t) => JsonResult::Ok(t),
+            Err(e) => JsonResult::Err {
+                year: puzzle.year,
+                day: puzzle.day,
+                error: e.to_string(),
+            },
+        }
+    }
+}
+
+/// Runs @puzzle on its input on its own thread, so that panics are contained
+fn run_day(inputs: &Inputs, puzzle: &Puzzle) -> Result<RunResult, AocError> {
+    let input = inputs.read(puzzle)?;
+    runner::run_isolated(*puzzle, input, None)
+}
+
+const ANSWER_WIDTH: usize = 20;
+
+fn print_header(benchmark: bool) {
+    print!("{:>4}  {:>4}  {:<ANSWER_WIDTH$}", "day", "part", "answer");
+
+    if benchmark {
+        print!("  {:>10}", "time");
+    }
+
+    if alloc::is_enabled() {
+        print!("  {:>10}  {:>10}  {:>10}", "peak", "allocs", "allocated");
+    }
+
+    println!();
+}
+
+/// Prints one row per part of @result. Multi-line answers (like rendered screens)
+/// and errors are printed below their row, lined up with the answer column
+fn print_result(result: &RunResult, benchmark: bool) {
+    for part in 1..=2 {
+        let pr = result.part(part).unwrap();
+        let answer = match &pr.error {
+            Some(e) => format!("Error! ({})", e),
+            None => pr.answer.to_string(),
+        };
+        let multiline = answer.trim_end().contains('\n') || pr.error.is_some();
+
+        print!(
+            "{:>4}  {:>4}  {:<ANSWER_WIDTH$}",
+            result.day,
+            part,
+            if multiline { "" } else { answer.as_str() }
+        );
+
+        if benchmark {
+            print!("  {:>10}", format!("{:.2?}", pr.time));
+        }
 
-    if let Some(text) = send_request(client, key, &url).await {
-        input.write_all(text.as_bytes())?;
+        if let Some(mem) = pr.memory {
+            print!(
+                "  {:>10}  {:>10}  {:>10}",
+                format_bytes(mem.peak as u64),
+                mem.allocations,
+                format_bytes(mem.bytes)
+            );
+        }
+
+        println!();
+
+        if multiline {
+            for line in answer.lines() {
+                println!("{:12}{}", "", line);
+            }
+        }
+    }
+}
+
+/// Formats @bytes with a binary unit, like 1.50 MiB
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} B", bytes)
     } else {
+        format!("{:.2} {}", size, UNITS[unit])
+    }
+}
+
+struct BenchOptions {
+    warmup: usize,
+    iterations: usize,
+    compare: Option<String>,
+    threshold: f64,
+    format: Format,
+}
+
+fn bench(year: &str, day: Option<usize>, opts: BenchOptions) -> io::Result<()> {
+    let registry = registry();
+    let year = year.parse::<u32>().map_err(io::Error::other)?;
+    let puzzles = select(&registry, year, day);
+    let inputs = Inputs::locate();
+    let baseline = match &opts.compare {
+        Some(r) => Some(
+            history::resolve(r)
+                .ok_or_else(|| io::Error::other(format!("Unknown revision: {}", r)))?,
+        ),
+        None => None,
+    };
+
+    let mut results = Vec::new();
+    let mut bench_day = |puzzle: &Puzzle| -> io::Result<BenchResult> {
+        let input = inputs.read(puzzle)?;
+        let result =
+            bench::bench(puzzle, &input, opts.warmup, opts.iterations).map_err(io::Error::other)?;
+
+        results.push(result.clone());
+        Ok(result)
+    };
+
+    let json = match opts.format {
+        Format::Table => {
+            println!(
+                "{:>4}  {:>5}  {:>10}  {:>10}  {:>10}  {:>10}",
+                "day", "step", "min", "median", "mean", "stddev"
+            );
+
+            for puzzle in puzzles {
+                match bench_day(puzzle) {
+                    Ok(result) => {
+                        for (step, stats) in result.steps() {
+                            println!(
+                                "{:>4}  {:>5}  {:>10}  {:>10}  {:>10}  {:>10}",
+                                result.day,
+                                step,
+                                format!("{:.2?}", stats.min),
+                                format!("{:.2?}", stats.median),
+                                format!("{:.2?}", stats.mean),
+                                format!("{:.2?}", stats.stddev)
+                            );
+                        }
+                    }
+                    Err(e) => println!("{:>4}  {:>5}  Error! ({:?})", puzzle.day, "", e),
+                }
+            }
+
+            None
+        }
+        Format::Json => Some(
+            puzzles
+                .into_iter()
+                .map(|puzzle| JsonResult::new(puzzle, bench_day(puzzle)))
+                .collect::<Vec<JsonResult<BenchResult>>>(),
+        ),
+    };
+
+    let history = History::new(&input::project_root().join(HISTORY_FILE));
+    let comparisons = match &baseline {
+        Some(commit) => history::compare(&history.load()?, commit, &results, opts.threshold),
+        None => Vec::new(),
+    };
+
+    let rev = Revision::current();
+    save_timings(
+        &results
+            .iter()
+            .flat_map(|r| Record::from_bench(&rev, r))
+            .collect::<Vec<Record>>(),
+    );
+
+    if let Some(json) = json {
+        if let Some(commit) = &baseline {
+            let value = serde_json::json!({
+                "results": json,
+                "baseline": commit,
+                "comparisons": comparisons,
+            });
+
+            println!("{}", serde_json::to_string_pretty(&value)?);
+        } else {
+            println!("{}", serde_json::to_string_pretty(&json)?);
+        }
+    } else if let Some(commit) = &baseline {
+        println!();
+        print_comparisons(opts.compare.as_deref().unwrap_or(commit), &comparisons);
+    }
+
+    let slower = comparisons.iter().filter(|c| c.slower).count();
+    if slower > 0 {
+        Err(io::Error::other(format!(
+            "{} steps got slower than {}",
+            slower,
+            opts.compare.unwrap_or_default()
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+fn print_comparisons(name: &str, comparisons: &[Comparison]) {
+    if comparisons.is_empty() {
+        println!("No timings recorded for {}", name);
+        return;
+    }
+
+    println!(
+        "{:>4}  {:>5}  {:>10}  {:>10}  {:>8}  compared to {}",
+        "day", "step", "baseline", "median", "change", name
+    );
+
+    for c in comparisons {
+        println!(
+            "{:>4}  {:>5}  {:>10}  {:>10}  {:>+7.1}%  {}",
+            c.day,
+            c.step,
+            format!("{:.2?}", c.baseline),
+            format!("{:.2?}", c.current.median),
+            c.change() * 100.0,
+            match (c.slower, c.dirty_baseline) {
+                (true, true) => "SLOWER (dirty baseline)",
+                (true, false) => "SLOWER",
+                (false, true) => "(dirty baseline)",
+                (false, false) => "",
+            }
+        );
+    }
+}
+
+fn verify(years: &[&str], day: Option<usize>) -> io::Result<()> {
+    let registry = registry();
+    let inputs = Inputs::locate();
+    let answers_dir = input::project_root().join(ANSWERS_DIR);
+    let mut failed = 0;
+
+    println!("{:>4}  {:>4}  {:>4}  status", "year", "day", "part");
+
+    for year in years {
+        let year = year.parse::<u32>().map_err(io::Error::other)?;
+        let answers = YearAnswers::load(&answers_dir, year)?;
+
+        for puzzle in select(&registry, year, day) {
+            let result = match run_day(&inputs, puzzle) {
+                Ok(result) => result,
+                Err(e) => {
+                    println!("{:>4}  {:>4}  {:>4}  Error! ({})", year, puzzle.day, "", e);
+                    continue;
+                }
+            };
+
+            for part in 1..=2 {
+                let pr = result.part(part).unwrap();
+                let actual = &pr.answer;
+                let verdict = Verdict::check(answers.get(puzzle.day, part), actual);
+
+                print!("{:>4}  {:>4}  {:>4}  {}", year, puzzle.day, part, verdict);
+
+                if let Verdict::Fail { expected } = &verdict {
+                    failed += 1;
+
+                    match &pr.error {
+                        Some(e) => print!(" (expected {}, {})", expected, e),
+                        None => print!(" (expected {}, got {})", expected, actual),
+                    }
+                }
+
+                println!();
+            }
+        }
+    }
+
+    if failed > 0 {
+        Err(io::Error::other(format!(
+            "{} answers failed verification",
+            failed
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Submits @answer for @part of @year @day, or the solution's answer if @answer is
+/// None. Nothing is submitted if a correct answer was already recorded
+async fn submit(
+    client: &AocClient,
+    year: &str,
+    day: usize,
+    part: usize,
+    answer: Option<&str>,
+) -> io::Result<()> {
+    let registry = registry();
+    let year = year.parse::<u32>().map_err(io::Error::other)?;
+    let puzzle = registry
+        .get(year, day)
+        .ok_or_else(|| io::Error::other(format!("Invalid day: {}", day)))?;
+
+    let answer = match answer {
+        Some(a) => a.parse::<Answer>().unwrap(),
+        None => {
+            let result = run_day(&Inputs::locate(), puzzle).map_err(io::Error::other)?;
+            let pr = result.part(part).unwrap();
+
+            if let Some(e) = &pr.error {
+                return Err(io::Error::other(format!(
+                    "{} day {} part {} failed: {}",
+                    year, day, part, e
+                )));
+            }
+
+            pr.answer.clone()
+        }
+    };
+
+    if !answer.is_solved() {
         return Err(io::Error::other(format!(
-            "Error getting puzzle input for {} day {}",
-            year, day
+            "{} day {} part {} is unsolved",
+            year, day, part
         )));
     }
 
+    let mut answers = YearAnswers::load(&input::project_root().join(ANSWERS_DIR), year)?;
+
+    if let Some(expected) = answers.get(day, part) {
+        println!(
+            "Already recorded {} as the answer, {} is a {}",
+            expected,
+            answer,
+            Verdict::check(Some(expected), &answer)
+        );
+        return Ok(());
+    }
+
+    println!(
+        "Submitting {} for {} day {} part {}",
+        answer, year, day, part
+    );
+    let submission = client.submit(year, day, part, &answer).await?;
+    println!("{}", submission);
+
+    match submission {
+        Submission::Correct => {
+            answers.set(day, part, answer);
+            answers.save()
+        }
+        Submission::AlreadySolved => Ok(()),
+        _ => Err(io::Error::other("Answer was not accepted")),
+    }
+}
+
+/// Saves the description of @year @day as Markdown, along with its first example
+async fn fetch(client: &AocClient, year: &str, day: usize) -> io::Result<()> {
+    let year = year.parse::<u32>().map_err(io::Error::other)?;
+    let html = client.puzzle(year, day).await?;
+
+    let dir = input::project_root().join("puzzles").join(year.to_string());
+    let path = dir.join(format!("day{}.md", day));
+    input::write_atomic(&path, &description::to_markdown(&html))?;
+    println!("Saved description to {}", path.display());
+
+    let example_path = Inputs::locate()
+        .year_dir(year)
+        .join(format!("day{}_example.txt", day));
+
+    if example_path.exists() {
+        println!("{} already exists", example_path.display());
+    } else if let Some(example) = description::example(&html) {
+        input::write_atomic(&example_path, &example)?;
+        println!("Saved example to {}", example_path.display());
+    } else {
+        println!("No example found");
+    }
+
+    Ok(())
+}
+
+/// Writes a report of every day of @year to @out. Days are run on their inputs,
+/// unless @from_history is set, in which case the last recorded run of each is used
+fn report(
+    year: u32,
+    out: &Path,
+    redact: bool,
+    from_history: bool,
+    timeout: Option<Duration>,
+) -> io::Result<()> {
+    let registry = registry();
+    let puzzles = registry.year(year).to_vec();
+    let mut results: Vec<Result<RunResult, String>> = puzzles
+        .iter()
+        .map(|_| Err(String::from("not run")))
+        .collect();
+
+    if from_history {
+        let history = History::new(&input::project_root().join(HISTORY_FILE)).load()?;
+
+        for (puzzle, result) in puzzles.iter().zip(&mut results) {
+            *result = history::last_run(&history, year, puzzle.day)
+                .ok_or_else(|| String::from("no recorded run"));
+        }
+    } else {
+        let inputs = Inputs::locate();
+        let opts = RunOptions { jobs: 1, timeout };
+        let rx = runner::run_all(puzzles.clone(), opts, move |puzzle| inputs.read(puzzle));
+
+        for (i, result) in rx {
+            results[i] = result.map_err(|e| e.to_string());
+        }
+
+        let rev = Revision::current();
+        let records: Vec<Record> = results
+            .iter()
+            .flatten()
+            .flat_map(|r| Record::from_run(&rev, r))
+            .collect();
+        save_timings(&records);
+    }
+
+    let src = input::project_root().join("src").join(year.to_string());
+    let days = puzzles
+        .iter()
+        .zip(results)
+        .map(|(puzzle, result)| DayReport {
+            day: puzzle.day,
+            result,
+            modules: fs::read_to_string(src.join(format!("day{}.rs", puzzle.day)))
+                .map(|source| report::modules_used(&source))
+                .unwrap_or_default(),
+        })
+        .collect();
+    let report = Report { year, days, redact };
+
+    let text = match out.extension().and_then(|e| e.to_str()) {
+        Some("md" | "markdown") => report.to_markdown(),
+        _ => report.to_html(),
+    };
+
+    fs::write(out, text)?;
+    println!("Wrote {}", out.display());
     Ok(())
 }
 
-fn create_client() -> Client {
-    Client::builder()
-        .user_agent("benhartcheatham@gmail.com")
-        .cookie_store(true)
-        .build()
-        .unwrap()
+/// Prints a calendar of the progress on each day of @years
+fn status(years: &[&str]) -> io::Result<()> {
+    let registry = registry();
+    let inputs = Inputs::locate();
+    let root = input::project_root();
+    let history = History::new(&root.join(HISTORY_FILE)).load()?;
+
+    for year in years {
+        let year: u32 = year.parse().unwrap();
+        let answers = YearAnswers::load(&root.join(ANSWERS_DIR), year)?;
+        let days = status::year(year, registry.year(year), &inputs, &answers, &history);
+        let count = |s: PartStatus| {
+            days.iter()
+                .flat_map(|d| d.parts)
+                .filter(|p| *p == s)
+                .count()
+        };
+
+        println!(
+            "{}: {} solutions, {} parts verified, {} recorded, {} unsolved",
+            year,
+            days.iter().filter(|d| d.solution).count(),
+            count(PartStatus::Verified),
+            count(PartStatus::Recorded),
+            count(PartStatus::Unsolved)
+        );
+
+        for week in days.chunks(5) {
+            let cells: Vec<String> = week
+                .iter()
+                .map(|d| {
+                    let flag = |set, c| if set { c } else { '-' };
+
+                    format!(
+                        "{:>2} {}{}{} {}{} {:>8}",
+                        d.day,
+                        flag(d.solution, 's'),
+                        flag(d.input, 'i'),
+                        flag(d.example, 'e'),
+                        d.parts[0].symbol(),
+                        d.parts[1].symbol(),
+                        d.runtime.map_or(String::new(), |t| format!("{:.1?}", t))
+                    )
+                })
+                .collect();
+
+            println!("{}", cells.join(" | "));
+        }
+
+        println!();
+    }
+
+    println!("s: solution  i: input  e: example");
+    println!(
+        "parts: * verified  + recorded, not run  o solved, not recorded  ! wrong  . unsolved"
+    );
+    Ok(())
 }
 
-async fn send_request(client: &Client, session: &str, url: &str) -> Option<String> {
-    let mut req = client.get(url).build().unwrap();
-    req.headers_mut().insert(
-        COOKIE,
-        HeaderValue::from_str(&format!("session={}", session.trim())).unwrap(),
+/// Prints the standings of @board, with the stars each member earned per day,
+/// followed by how long after each day unlocked its parts were completed
+fn print_leaderboard(board: &Leaderboard, year: u32) {
+    let standings = board.standings();
+    let width = standings
+        .iter()
+        .map(|m| m.display_name().len())
+        .max()
+        .unwrap_or(0);
+    let tens: String = (1..=25)
+        .map(|d| {
+            if d < 10 {
+                ' '
+            } else {
+                char::from(b'0' + d / 10)
+            }
+        })
+        .collect();
+    let ones: String = (1..=25).map(|d| char::from(b'0' + d % 10)).collect();
+
+    println!("{:>4}  {:>5}  {:>5}  {}", "", "", "", tens);
+    println!(
+        "{:>4}  {:>5}  {:>5}  {}  name",
+        "rank", "score", "stars", ones
     );
 
-    if let Ok(response) = client.execute(req).await {
-        let text = response.text().await.unwrap();
-        Some(text)
-    } else {
-        None
+    for (rank, member) in standings.iter().enumerate() {
+        // * for both stars, + for only the first
+        let stars: String = (1..=25)
+            .map(|d| match member.stars_on(d) {
+                0 => '.',
+                1 => '+',
+                _ => '*',
+            })
+            .collect();
+
+        println!(
+            "{:>4}  {:>5}  {:>5}  {}  {}",
+            rank + 1,
+            member.local_score,
+            member.stars,
+            stars,
+            member.display_name()
+        );
+    }
+
+    for member in standings.iter().filter(|m| m.stars > 0) {
+        println!(
+            "\n{:<width$}  {:>4}  {:>10}  {:>10}",
+            member.display_name(),
+            "day",
+            "part 1",
+            "part 2"
+        );
+
+        for &day in member.completion_day_level.keys() {
+            let unlock = leaderboard::unlock_time(year, day);
+            let elapsed = |part| {
+                member.completed(day, part).map_or(String::from("-"), |ts| {
+                    let secs = ts.saturating_sub(unlock);
+                    format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+                })
+            };
+
+            println!(
+                "{:<width$}  {:>4}  {:>10}  {:>10}",
+                "",
+                day,
+                elapsed(1),
+                elapsed(2)
+            );
+        }
     }
 }
 
-fn run(year: &str, day: Option<usize>, benchmark: bool) -> io::Result<()> {
-    match year {
-        "2022" => year22::run(day, benchmark),
-        "2023" => year23::run(day),
-        "2024" => year24::run(day, benchmark),
-        "2025" => year25::run(day, benchmark),
-        _ => Ok(()),
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let inputs = Inputs::new(&Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"));
+        let mut failures = Vec::new();
+
+        for puzzle in registry().iter() {
+            // examples without answers (like freshly created days) have nothing to check
+            for example in puzzle
+                .examples
+                .iter()
+                .filter(|e| e.part1.is_some() || e.part2.is_some())
+            {
+                let path = inputs.example_path(puzzle, example);
+                let input = input::read_file(&path).unwrap_or_else(|e| panic!("{}", e));
+                let result = match puzzle.run(&input) {
+                    Ok(result) => result,
+                    Err(e) => {
+                        failures.push(format!("{}: {}", path.display(), e));
+                        continue;
+                    }
+                };
+
+                for part in 1..=2 {
+                    let expected = example.part(part);
+                    let pr = result.part(part).unwrap();
+                    let actual = &pr.answer;
+
+                    if let (Some(e), Some(_)) = (&pr.error, &expected) {
+                        failures.push(format!("{} part {}: {}", path.display(), part, e));
+                    } else if let Verdict::Fail { expected } =
+                        Verdict::check(expected.as_ref(), actual)
+                    {
+                        failures.push(format!(
+                            "{} part {}: expected {}, got {}",
+                            path.display(),
+                            part,
+                            expected,
+                            actual
+                        ));
+                    }
+                }
+            }
+        }
+
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 << 30), "3.00 GiB");
+    }
+
+    #[test]
+    fn timeouts() {
+        assert_eq!(parse_timeout("1.5"), Ok(Duration::from_millis(1500)));
+
+        for bad in ["0", "-2", "NaN", "inf", "1e300", "soon"] {
+            assert!(parse_timeout(bad).is_err(), "{} was accepted", bad);
+        }
+    }
+
+    #[test]
+    fn registry_matches_years() {
+        let registry = registry();
+
+        assert_eq!(
+            registry.years().map(|y| y.to_string()).collect::<Vec<_>>(),
+            YEARS
+        );
+
+        // days can be created out of order, but have to be registered in order
+        for year in registry.years() {
+            let puzzles = registry.year(year);
+
+            assert!(puzzles.iter().all(|p| p.year == year));
+            assert!(puzzles.windows(2).all(|w| w[0].day < w[1].day));
+        }
     }
 }
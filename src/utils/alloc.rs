@@ -0,0 +1,146 @@
+//! Counting allocator for profiling how much memory solutions use. It only does
+//! anything in binaries that install it as their global allocator, and only counts
+//! once enable() is called, so it costs one atomic load per allocation otherwise
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+
+use serde::Serialize;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// Wraps the system allocator, counting allocations while enabled
+pub struct CountingAlloc;
+
+fn record_alloc(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(size as u64, Ordering::Relaxed);
+
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+fn record_dealloc(size: usize) {
+    // memory allocated before counting was enabled can be freed after, so this
+    // can't be allowed to wrap around
+    let _ = CURRENT.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |c| {
+        Some(c.saturating_sub(size))
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+
+        if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            record_alloc(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+
+        if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            record_alloc(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+
+        if ENABLED.load(Ordering::Relaxed) {
+            record_dealloc(layout.size());
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = unsafe { System.realloc(ptr, layout, new_size) };
+
+        if !new.is_null() && ENABLED.load(Ordering::Relaxed) {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+
+        new
+    }
+}
+
+/// Starts counting allocations
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Memory used while running some code. Counts are process wide, so anything
+/// running at the same time is counted too
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct MemStats {
+    /// Most heap memory in use at once, on top of what was in use before
+    #[serde(rename = "peak_bytes")]
+    pub peak: usize,
+    pub allocations: u64,
+    /// Total size of every allocation
+    #[serde(rename = "allocated_bytes")]
+    pub bytes: u64,
+}
+
+/// Runs @f and measures its memory use. Returns None for the stats if counting
+/// isn't enabled
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<MemStats>) {
+    if !is_enabled() {
+        return (f(), None);
+    }
+
+    let base = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(base, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+
+    let ret = f();
+
+    let stats = MemStats {
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(base),
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+    };
+
+    (ret, Some(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hint::black_box;
+
+    #[global_allocator]
+    static ALLOC: CountingAlloc = CountingAlloc;
+
+    #[test]
+    fn counts_allocations() {
+        enable();
+
+        let (len, stats) = measure(|| {
+            let big = black_box(vec![0_u8; 1 << 20]);
+            let small = black_box(vec![1_u32; 16]);
+
+            big.len() + small.len()
+        });
+        let stats = stats.unwrap();
+
+        assert_eq!(len, (1 << 20) + 16);
+        assert!(stats.allocations >= 2);
+        assert!(stats.bytes >= (1 << 20) + 64);
+        assert!(stats.peak >= 1 << 20);
+    }
+}
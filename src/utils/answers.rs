@@ -0,0 +1,207 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::input;
+use crate::solution::Answer;
+
+/// Directory answer files are stored in, one file per year
+pub const ANSWERS_DIR: &str = "answers";
+
+/// Accepted answers for both parts of a day
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Answer>,
+}
+
+impl DayAnswers {
+    fn part(&self, part: usize) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+
+    fn part_mut(&mut self, part: usize) -> Option<&mut Option<Answer>> {
+        match part {
+            1 => Some(&mut self.part1),
+            2 => Some(&mut self.part2),
+            _ => None,
+        }
+    }
+}
+
+/// Accepted answers for every day of a year. Stored as <dir>/<year>.toml, with
+/// one table per day:
+///
+/// [day1]
+/// part1 = 1234
+/// part2 = "ABCD"
+#[derive(Debug)]
+pub struct YearAnswers {
+    path: PathBuf,
+    pub year: u32,
+    days: BTreeMap<usize, DayAnswers>,
+}
+
+impl YearAnswers {
+    /// Loads the answers for @year from @dir. If there isn't an answer file
+    /// for @year yet, returns an empty set of answers
+    pub fn load(dir: &Path, year: u32) -> io::Result<Self> {
+        let path = dir.join(format!("{}.toml", year));
+        let mut days = BTreeMap::new();
+
+        match fs::read_to_string(&path) {
+            Ok(text) => {
+                let table: toml::Table = toml::from_str(&text).map_err(|e| invalid(&path, e))?;
+
+                for (key, value) in table {
+                    let day = key
+                        .strip_prefix("day")
+                        .and_then(|d| d.parse::<usize>().ok())
+                        .ok_or_else(|| invalid(&path, format!("invalid day \"{}\"", key)))?;
+                    let answers: DayAnswers = value.try_into().map_err(|e| invalid(&path, e))?;
+
+                    days.insert(day, answers);
+                }
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => (),
+            Err(e) => return Err(e),
+        }
+
+        Ok(YearAnswers { path, year, days })
+    }
+
+    /// Gets the accepted answer for @day @part, if one has been recorded
+    pub fn get(&self, day: usize, part: usize) -> Option<&Answer> {
+        self.days.get(&day).and_then(|d| d.part(part))
+    }
+
+    /// Records @answer as the accepted answer for @day @part
+    pub fn set(&mut self, day: usize, part: usize, answer: Answer) {
+        if let Some(slot) = self.days.entry(day).or_default().part_mut(part) {
+            *slot = Some(answer);
+        }
+    }
+
+    /// Writes these answers back to the file they were loaded from
+    pub fn save(&self) -> io::Result<()> {
+        let mut table = toml::Table::new();
+
+        for (day, answers) in &self.days {
+            let value = toml::Value::try_from(answers).map_err(|e| invalid(&self.path, e))?;
+            table.insert(format!("day{}", day), value);
+        }
+
+        let text = toml::to_string(&table).map_err(|e| invalid(&self.path, e))?;
+        input::write_atomic(&self.path, &text)
+    }
+}
+
+fn invalid(path: &Path, e: impl Display) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("{}: {}", path.display(), e),
+    )
+}
+
+/// Result of checking a computed answer against the recorded one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer },
+    Missing,
+}
+
+impl Verdict {
+    /// Compares @actual against @expected. Answers are compared by their text,
+    /// ignoring trailing whitespace, so that a number recorded as a string still
+    /// matches
+    pub fn check(expected: Option<&Answer>, actual: &Answer) -> Self {
+        match expected {
+            None => Verdict::Missing,
+            Some(e) if actual.is_solved() && same(e, actual) => Verdict::Pass,
+            Some(e) => Verdict::Fail {
+                expected: e.clone(),
+            },
+        }
+    }
+}
+
+fn same(a: &Answer, b: &Answer) -> bool {
+    a.to_string().trim_end() == b.to_string().trim_end()
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { .. } => write!(f, "FAIL"),
+            Verdict::Missing => write!(f, "MISSING"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn save_and_load() {
+        let dir = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        let mut answers = YearAnswers::load(&dir, 2022).unwrap();
+
+        answers.set(10, 2, Answer::from("EHPZPJGL"));
+        answers.set(2, 1, Answer::from(15_u64));
+        answers.set(1, 1, Answer::from(24000_u64));
+        answers.set(1, 2, Answer::from(45000_u64));
+        answers.save().unwrap();
+
+        let text = fs::read_to_string(dir.join("2022.toml")).unwrap();
+        assert!(text.find("[day1]").unwrap() < text.find("[day2]").unwrap());
+        assert!(text.find("[day2]").unwrap() < text.find("[day10]").unwrap());
+
+        let loaded = YearAnswers::load(&dir, 2022).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(loaded.get(1, 2), Some(&Answer::Integer(45000)));
+        assert_eq!(loaded.get(10, 2), Some(&Answer::from("EHPZPJGL")));
+        assert_eq!(loaded.get(10, 1), None);
+        assert_eq!(loaded.get(3, 1), None);
+    }
+
+    #[test]
+    fn verdicts() {
+        let expected = Answer::Integer(42);
+
+        assert_eq!(
+            Verdict::check(Some(&expected), &42_u32.into()),
+            Verdict::Pass
+        );
+        assert_eq!(
+            Verdict::check(Some(&"42".into()), &42_u32.into()),
+            Verdict::Pass
+        );
+        assert_eq!(
+            Verdict::check(Some(&expected), &41_u32.into()),
+            Verdict::Fail {
+                expected: expected.clone()
+            }
+        );
+        assert_eq!(
+            Verdict::check(Some(&expected), &Answer::Unsolved),
+            Verdict::Fail {
+                expected: expected.clone()
+            }
+        );
+        assert_eq!(Verdict::check(None, &42_u32.into()), Verdict::Missing);
+    }
+}
@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::error::AocError;
+use crate::solution::as_nanos;
+use crate::solution::{Puzzle, RunResult};
+
+/// Summary of a set of timing samples
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Stats {
+    #[serde(rename = "min_ns", serialize_with = "as_nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "as_nanos")]
+    pub median: Duration,
+    #[serde(rename = "mean_ns", serialize_with = "as_nanos")]
+    pub mean: Duration,
+    #[serde(rename = "stddev_ns", serialize_with = "as_nanos")]
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Computes the Stats of @samples. @samples must not be empty
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let mean = sorted.iter().map(|d| d.as_secs_f64()).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Timing Stats for each step of a Puzzle
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchResult {
+    pub year: u32,
+    pub day: usize,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl BenchResult {
+    /// Gets the Stats for each step, along with the step's name
+    pub fn steps(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part1", &self.part1),
+            ("part2", &self.part2),
+        ]
+    }
+}
+
+/// Runs @puzzle on @input @warmup times without measuring it, then
+/// @iterations more times to collect timing samples. Fails if @input can't be parsed
+pub fn bench(
+    puzzle: &Puzzle,
+    input: &str,
+    warmup: usize,
+    iterations: usize,
+) -> Result<BenchResult, AocError> {
+    for _ in 0..warmup {
+        puzzle.run(input)?;
+    }
+
+    let runs = (0..iterations.max(1))
+        .map(|_| puzzle.run(input))
+        .collect::<Result<Vec<RunResult>, AocError>>()?;
+    let samples = |f: fn(&RunResult) -> Duration| runs.iter().map(f).collect::<Vec<Duration>>();
+
+    Ok(BenchResult {
+        year: puzzle.year,
+        day: puzzle.day,
+        iterations: runs.len(),
+        parse: Stats::new(&samples(|r| r.parse)),
+        part1: Stats::new(&samples(|r| r.part1.time)),
+        part2: Stats::new(&samples(|r| r.part2.time)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let samples: Vec<Duration> = [4, 1, 3, 2]
+            .into_iter()
+            .map(Duration::from_millis)
+            .collect();
+        let stats = Stats::new(&samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean.as_micros(), 2500);
+        assert_eq!(stats.stddev.as_micros(), 1118);
+
+        let stats = Stats::new(&samples[..3]);
+        assert_eq!(stats.median, Duration::from_millis(3));
+    }
+}
@@ -0,0 +1,491 @@
+use std::fmt::Display;
+use std::io;
+use std::sync::LazyLock;
+use std::time::{Duration, Instant};
+
+use regex::Regex;
+use reqwest::header::{COOKIE, HeaderValue};
+use reqwest::{Client, RequestBuilder, StatusCode};
+use tokio::sync::Mutex;
+
+use crate::solution::Answer;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const USER_AGENT: &str = "benhartcheatham@gmail.com";
+
+/// Minimum time between requests, so that the site isn't hammered
+pub const THROTTLE: Duration = Duration::from_secs(1);
+/// How many times a request that failed for a transient reason is retried
+pub const RETRIES: u32 = 3;
+/// Delay before the first retry. It doubles with each retry
+pub const BACKOFF: Duration = Duration::from_secs(2);
+
+/// Client for the Advent of Code website, authenticated with a session cookie
+pub struct AocClient {
+    client: Client,
+    base_url: String,
+    session: String,
+    throttle: Duration,
+    retries: u32,
+    backoff: Duration,
+    last_request: Mutex<Option<Instant>>,
+}
+
+/// Why a request failed, and whether it's worth trying again
+struct Failure {
+    error: io::Error,
+    transient: bool,
+}
+
+impl AocClient {
+    pub fn new(session: &str) -> Self {
+        AocClient::with_base_url(BASE_URL, session)
+    }
+
+    /// Creates a client that sends its requests to @base_url instead of the real
+    /// site, which is useful for testing
+    pub fn with_base_url(base_url: &str, session: &str) -> Self {
+        let client = Client::builder()
+            .user_agent(USER_AGENT)
+            .build()
+            .expect("Could not create HTTP client");
+
+        AocClient {
+            client,
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.trim().to_owned(),
+            throttle: THROTTLE,
+            retries: RETRIES,
+            backoff: BACKOFF,
+            last_request: Mutex::new(None),
+        }
+    }
+
+    /// Sets the minimum time between requests
+    pub fn with_throttle(mut self, throttle: Duration) -> Self {
+        self.throttle = throttle;
+        self
+    }
+
+    /// Sets how many times failed GET requests are retried, and the delay
+    /// before the first retry
+    pub fn with_retries(mut self, retries: u32, backoff: Duration) -> Self {
+        self.retries = retries;
+        self.backoff = backoff;
+        self
+    }
+
+    /// Waits until enough time has passed since the last request
+    async fn wait_turn(&self) {
+        let mut last = self.last_request.lock().await;
+
+        if let Some(t) = *last {
+            tokio::time::sleep(self.throttle.saturating_sub(t.elapsed())).await;
+        }
+
+        *last = Some(Instant::now());
+    }
+
+    fn cookie(&self) -> io::Result<HeaderValue> {
+        HeaderValue::from_str(&format!("session={}", self.session)).map_err(io::Error::other)
+    }
+
+    /// Sends @request and gets the text of the response. Responses for logged out
+    /// users and anything but 200 OK are errors, so that they're never mistaken for
+    /// real pages
+    async fn send(&self, request: RequestBuilder, what: &str) -> Result<String, Failure> {
+        let cookie = self.cookie().map_err(|error| Failure {
+            error,
+            transient: false,
+        })?;
+
+        self.wait_turn().await;
+        let response = request
+            .header(COOKIE, cookie)
+            .send()
+            .await
+            .map_err(|e| Failure {
+                transient: e.is_connect() || e.is_timeout() || e.is_request(),
+                error: io::Error::other(format!("{} failed: {}", what, e)),
+            })?;
+        let status = response.status();
+        let text = response.text().await.map_err(|e| Failure {
+            transient: true,
+            error: io::Error::other(format!("{} failed: {}", what, e)),
+        })?;
+
+        if is_logged_out(&text) {
+            return Err(Failure {
+                error: io::Error::new(
+                    io::ErrorKind::PermissionDenied,
+                    format!(
+                        "{} failed: not logged in, the session cookie is invalid or expired",
+                        what
+                    ),
+                ),
+                transient: false,
+            });
+        }
+
+        if status != StatusCode::OK {
+            return Err(Failure {
+                error: io::Error::other(format!("{} failed: {}", what, status)),
+                transient: status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS,
+            });
+        }
+
+        Ok(text)
+    }
+
+    /// Gets the page at @path (relative to the site's root). Transient failures,
+    /// like server errors or dropped connections, are retried with exponential backoff
+    pub async fn get(&self, path: &str) -> io::Result<String> {
+        let url = format!("{}/{}", self.base_url, path.trim_start_matches('/'));
+        let what = format!("Getting {}", path);
+        let mut delay = self.backoff;
+
+        for _ in 0..self.retries {
+            match self.send(self.client.get(&url), &what).await {
+                Ok(text) => return Ok(text),
+                Err(f) if f.transient => {
+                    eprintln!("{}, retrying in {:?}", f.error, delay);
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                }
+                Err(f) => return Err(f.error),
+            }
+        }
+
+        self.send(self.client.get(&url), &what)
+            .await
+            .map_err(|f| f.error)
+    }
+
+    /// Gets the HTML page describing @year @day
+    pub async fn puzzle(&self, year: u32, day: usize) -> io::Result<String> {
+        self.get(&format!("{}/day/{}", year, day)).await
+    }
+
+    /// Gets the puzzle input for @year @day
+    pub async fn input(&self, year: u32, day: usize) -> io::Result<String> {
+        self.get(&format!("{}/day/{}/input", year, day)).await
+    }
+
+    /// Gets the JSON of private leaderboard @id for @year. The site asks that this
+    /// isn't requested more than once every 15 minutes
+    pub async fn leaderboard(&self, year: u32, id: u64) -> io::Result<String> {
+        self.get(&format!("{}/leaderboard/private/view/{}.json", year, id))
+            .await
+    }
+
+    /// Gets the name of the user the session belongs to, which also checks that
+    /// the session is valid
+    pub async fn user(&self) -> io::Result<String> {
+        let html = self.get("/").await?;
+
+        USER.captures(&html)
+            .map(|c| c[1].trim().to_owned())
+            .ok_or_else(|| io::Error::other("Could not find the user on the page"))
+    }
+
+    /// Submits @answer for @part of @year @day
+    pub async fn submit(
+        &self,
+        year: u32,
+        day: usize,
+        part: usize,
+        answer: &Answer,
+    ) -> io::Result<Submission> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let form = [("level", part.to_string()), ("answer", answer.to_string())];
+        // submitting isn't retried, since a retry could count as a second answer
+        let html = self
+            .send(self.client.post(url).form(&form), "Submitting answer")
+            .await
+            .map_err(|f| f.error)?;
+
+        Ok(Submission::parse(&html))
+    }
+}
+
+static USER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"<div class="user">([^<]*)"#).unwrap());
+
+/// Checks whether @text is what the site sends to users that aren't logged in:
+/// either a page with a login link, or the plain text asking to log in that
+/// is sent instead of an input
+pub fn is_logged_out(text: &str) -> bool {
+    text.contains("Please log in") || text.contains("[Log In]")
+}
+
+/// The site's verdict on a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Submission {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The part was already solved, or isn't unlocked yet
+    AlreadySolved,
+    /// An answer was submitted too recently. Contains how long to wait, if the
+    /// response said
+    RateLimited(Option<Duration>),
+    /// A response that couldn't be classified, with the text of its article
+    Unknown(String),
+}
+
+static ARTICLE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap());
+static TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^>]*>").unwrap());
+static WAIT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap());
+
+impl Submission {
+    /// Classifies the HTML page returned after submitting an answer
+    pub fn parse(html: &str) -> Self {
+        let text = match ARTICLE.captures(html) {
+            Some(c) => TAG.replace_all(&c[1], "").to_string(),
+            None => TAG.replace_all(html, "").to_string(),
+        };
+        let text = text.split_whitespace().collect::<Vec<&str>>().join(" ");
+
+        if text.contains("That's the right answer") {
+            Submission::Correct
+        } else if text.contains("your answer is too high") {
+            Submission::TooHigh
+        } else if text.contains("your answer is too low") {
+            Submission::TooLow
+        } else if text.contains("That's not the right answer") {
+            Submission::Wrong
+        } else if text.contains("Did you already complete it") {
+            Submission::AlreadySolved
+        } else if text.contains("You gave an answer too recently") {
+            let wait = WAIT.captures(&text).map(|c| {
+                let min = c.get(1).map_or(0, |m| m.as_str().parse::<u64>().unwrap());
+                let sec = c[2].parse::<u64>().unwrap();
+
+                Duration::from_secs(min * 60 + sec)
+            });
+
+            Submission::RateLimited(wait)
+        } else {
+            Submission::Unknown(text)
+        }
+    }
+}
+
+impl Display for Submission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Submission::Correct => write!(f, "Correct!"),
+            Submission::TooHigh => write!(f, "Wrong, the answer is too high"),
+            Submission::TooLow => write!(f, "Wrong, the answer is too low"),
+            Submission::Wrong => write!(f, "Wrong"),
+            Submission::AlreadySolved => write!(f, "Already solved"),
+            Submission::RateLimited(Some(wait)) => {
+                write!(f, "Answered too recently, wait {}s", wait.as_secs())
+            }
+            Submission::RateLimited(None) => write!(f, "Answered too recently"),
+            Submission::Unknown(text) => write!(f, "Unknown response: {}", text),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod mock {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    /// Serves one canned (status, body) response per request, in order, on a local
+    /// port. Returns the server's base URL and a receiver for the raw requests it got
+    pub(crate) fn serve(responses: Vec<(u16, String)>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut length = 0;
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+
+                    if let Some(n) = line.to_lowercase().strip_prefix("content-length:") {
+                        length = n.trim().parse().unwrap();
+                    }
+
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+
+                let mut buf = vec![0; length];
+                reader.read_exact(&mut buf).unwrap();
+                request.push_str(&String::from_utf8_lossy(&buf));
+
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+
+                let _ = tx.send(request);
+            }
+        });
+
+        (url, rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(text: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            text
+        )
+    }
+
+    #[test]
+    fn classify() {
+        let cases = [
+            (
+                "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.",
+                Submission::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.",
+                Submission::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Submission::TooLow,
+            ),
+            (
+                "That's not the right answer.  If you're stuck, <a href=\"/2024/day/1\">try again</a>.",
+                Submission::Wrong,
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Submission::AlreadySolved,
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.",
+                Submission::RateLimited(Some(Duration::from_secs(65))),
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait.",
+                Submission::RateLimited(Some(Duration::from_secs(34))),
+            ),
+        ];
+
+        for (text, expected) in cases {
+            assert_eq!(Submission::parse(&page(text)), expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn submit() {
+        let (url, requests) = mock::serve(vec![(200, page("That's the right answer!"))]);
+        let client = AocClient::with_base_url(&url, "abc123\n").with_throttle(Duration::ZERO);
+
+        let submission = client.submit(2024, 7, 2, &Answer::from(42)).await.unwrap();
+        let request = requests.recv().unwrap();
+
+        assert_eq!(submission, Submission::Correct);
+        assert!(request.starts_with("POST /2024/day/7/answer "));
+        assert!(request.contains("cookie: session=abc123\r\n"));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[tokio::test]
+    async fn get() {
+        let (url, requests) = mock::serve(vec![
+            (200, String::from("<article>day 7</article>")),
+            (404, String::from("Not Found")),
+        ]);
+        let client = AocClient::with_base_url(&url, "abc123").with_throttle(Duration::ZERO);
+
+        assert_eq!(
+            client.puzzle(2024, 7).await.unwrap(),
+            "<article>day 7</article>"
+        );
+        assert!(requests.recv().unwrap().starts_with("GET /2024/day/7 "));
+        assert!(client.puzzle(2024, 26).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn logged_out() {
+        let (url, _requests) = mock::serve(vec![
+            (
+                400,
+                String::from(
+                    "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+                ),
+            ),
+            (
+                200,
+                String::from(r#"<header><a href="/2024/auth/login">[Log In]</a></header>"#),
+            ),
+            (
+                200,
+                String::from(
+                    r#"<header><div class="user">someone <span class="star-count">4*</span></div></header>"#,
+                ),
+            ),
+        ]);
+        let client = AocClient::with_base_url(&url, "expired").with_throttle(Duration::ZERO);
+
+        let err = client.input(2024, 1).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+
+        let err = client.user().await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+
+        assert_eq!(client.user().await.unwrap(), "someone");
+    }
+
+    #[tokio::test]
+    async fn retries() {
+        let (url, requests) = mock::serve(vec![
+            (503, String::from("Service Unavailable")),
+            (502, String::from("Bad Gateway")),
+            (200, String::from("1 2 3\n")),
+            (404, String::from("Not Found")),
+        ]);
+        let client = AocClient::with_base_url(&url, "abc123")
+            .with_throttle(Duration::ZERO)
+            .with_retries(3, Duration::from_millis(1));
+
+        assert_eq!(client.input(2024, 1).await.unwrap(), "1 2 3\n");
+        assert!(client.input(2024, 2).await.is_err());
+        assert_eq!(requests.iter().count(), 4);
+    }
+
+    #[tokio::test]
+    async fn throttle() {
+        let (url, _requests) =
+            mock::serve(vec![(200, String::from("a")), (200, String::from("b"))]);
+        let client =
+            AocClient::with_base_url(&url, "abc123").with_throttle(Duration::from_millis(200));
+        let start = Instant::now();
+
+        client.get("/a").await.unwrap();
+        client.get("/b").await.unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+}
@@ -0,0 +1,324 @@
+//! Converts puzzle pages from the Advent of Code website into Markdown. The pages
+//! only use a handful of tags, so this handles those instead of being a general
+//! HTML converter
+
+use crate::client::BASE_URL;
+
+/// A piece of an HTML document
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(&'a str),
+}
+
+/// Splits @html into tags and text. Comments and doctypes are dropped
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+        } else if rest.starts_with('<') {
+            // a < that's never closed isn't a tag, like in the text "a <b"
+            let Some(end) = rest.find('>') else {
+                tokens.push(Token::Text(rest));
+                break;
+            };
+            let tag = rest[1..end].trim_end_matches('/').trim();
+
+            if let Some(name) = tag.strip_prefix('/') {
+                tokens.push(Token::Close(name.trim()));
+            } else if !tag.starts_with('!') {
+                let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+                tokens.push(Token::Open(name, attrs));
+            }
+
+            rest = &rest[end + 1..];
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            tokens.push(Token::Text(&rest[..end]));
+            rest = &rest[end..];
+        }
+    }
+
+    tokens
+}
+
+/// Gets the value of attribute @name from the attributes of a tag
+fn attr<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{}=\"", name))? + name.len() + 2;
+    let len = attrs[start..].find('"')?;
+
+    Some(&attrs[start..start + len])
+}
+
+/// Replaces the HTML entities the site uses with the characters they stand for
+fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(i) = rest.find('&') {
+        out.push_str(&rest[..i]);
+        rest = &rest[i..];
+
+        let Some(end) = rest.find(';').filter(|&end| end < 10) else {
+            out.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+
+        let c = match &rest[1..end] {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            e if e.starts_with("#x") => u32::from_str_radix(&e[2..], 16)
+                .ok()
+                .and_then(char::from_u32),
+            e if e.starts_with('#') => e[1..].parse().ok().and_then(char::from_u32),
+            _ => None,
+        };
+
+        match c {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// Gets the tokens inside each <article> in @tokens
+fn articles<'a>(tokens: &'a [Token<'a>]) -> Vec<&'a [Token<'a>]> {
+    let mut articles = Vec::new();
+    let mut start = None;
+
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Open("article", _) => start = Some(i + 1),
+            Token::Close("article") => {
+                if let Some(s) = start.take() {
+                    articles.push(&tokens[s..i]);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    articles
+}
+
+/// Converts the <article> sections of a puzzle page to Markdown. Each part of the
+/// puzzle is in its own article, so the result has every unlocked part
+pub fn to_markdown(html: &str) -> String {
+    let tokens = tokenize(html);
+    let mut md = String::new();
+
+    for article in articles(&tokens) {
+        let mut out = Markdown::default();
+
+        for token in article {
+            out.push(token);
+        }
+
+        md.push_str(out.text.trim());
+        md.push_str("\n\n");
+    }
+
+    md.trim_end().to_owned() + "\n"
+}
+
+/// State for converting the tokens of one article
+#[derive(Default)]
+struct Markdown<'a> {
+    text: String,
+    /// Inside a <pre>, where whitespace is kept as is
+    pre: bool,
+    /// Where the currently open inline <code> starts
+    code: Option<usize>,
+    /// Whether the open <code> is emphasized as a whole, as in <code><em>42</em></code>
+    code_em: bool,
+    /// Hrefs of the links that are currently open
+    links: Vec<&'a str>,
+}
+
+impl<'a> Markdown<'a> {
+    /// Starts a new block, separated from the previous one by a blank line
+    fn block(&mut self) {
+        let trimmed = self.text.trim_end_matches([' ', '\n']).len();
+        self.text.truncate(trimmed);
+
+        if !self.text.is_empty() {
+            self.text.push_str("\n\n");
+        }
+    }
+
+    fn push(&mut self, token: &Token<'a>) {
+        match *token {
+            Token::Open("h2", _) => {
+                self.block();
+                self.text.push_str("## ");
+            }
+            Token::Open("p", _) | Token::Close("p" | "h2" | "ul") => self.block(),
+            Token::Open("ul", _) => self.block(),
+            Token::Open("li", _) => {
+                let trimmed = self.text.trim_end_matches(' ').len();
+                self.text.truncate(trimmed);
+
+                if !self.text.is_empty() && !self.text.ends_with('\n') {
+                    self.text.push('\n');
+                }
+                self.text.push_str("- ");
+            }
+            Token::Open("pre", _) => {
+                self.block();
+                self.text.push_str("```\n");
+                self.pre = true;
+            }
+            Token::Close("pre") => {
+                if !self.text.ends_with('\n') {
+                    self.text.push('\n');
+                }
+                self.text.push_str("```");
+                self.pre = false;
+                self.block();
+            }
+            Token::Open("code", _) if !self.pre => {
+                self.code = Some(self.text.len());
+                self.text.push('`');
+            }
+            Token::Close("code") if !self.pre => {
+                self.text.push('`');
+
+                if self.code_em {
+                    self.text.push_str("**");
+                }
+
+                self.code = None;
+                self.code_em = false;
+            }
+            // Markdown can't emphasize inside code, so emphasis at the start of
+            // a code span is moved in front of it instead
+            Token::Open("em", _) if self.code.is_some() => {
+                if let Some(start) = self.code.filter(|&s| s + 1 == self.text.len()) {
+                    self.text.insert_str(start, "**");
+                    self.code = Some(start + 2);
+                    self.code_em = true;
+                }
+            }
+            Token::Open("em", _) | Token::Close("em") if !self.pre && self.code.is_none() => {
+                self.text.push_str("**");
+            }
+            Token::Open("a", attrs) => {
+                let href = attr(attrs, "href").unwrap_or("");
+                self.links.push(href);
+                self.text.push('[');
+            }
+            Token::Close("a") => {
+                let href = self.links.pop().unwrap_or("");
+
+                if href.starts_with('/') {
+                    self.text.push_str(&format!("]({}{})", BASE_URL, href));
+                } else {
+                    self.text.push_str(&format!("]({})", href));
+                }
+            }
+            Token::Open("br", _) => self.text.push('\n'),
+            Token::Text(text) if self.pre => self.text.push_str(&unescape(text)),
+            // outside of <pre>, runs of whitespace are collapsed into one space
+            Token::Text(text) => {
+                for c in unescape(text).chars() {
+                    if !c.is_whitespace() {
+                        self.text.push(c);
+                    } else if !self.text.is_empty() && !self.text.ends_with([' ', '\n']) {
+                        self.text.push(' ');
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+/// Gets the text of the first <pre><code> block in @html, which is usually the
+/// puzzle's example input
+pub fn example(html: &str) -> Option<String> {
+    let tokens = tokenize(html);
+    let start = tokens
+        .windows(2)
+        .position(|w| matches!(w, [Token::Open("pre", _), Token::Open("code", _)]))?;
+    let mut text = String::new();
+
+    for token in &tokens[start + 2..] {
+        match token {
+            Token::Text(t) => text.push_str(&unescape(t)),
+            Token::Close("code") => break,
+            _ => (),
+        }
+    }
+
+    Some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = include_str!("fixtures/puzzle.html");
+    const PAGE_SOLVED: &str = include_str!("fixtures/puzzle_solved.html");
+
+    #[test]
+    fn markdown() {
+        let md = to_markdown(PAGE);
+
+        assert_eq!(md, include_str!("fixtures/puzzle.md"));
+        assert!(!md.contains("part2"));
+    }
+
+    #[test]
+    fn markdown_both_parts() {
+        let md = to_markdown(PAGE_SOLVED);
+
+        assert!(md.starts_with("## --- Day 3: Crate Counting ---\n\n"));
+        assert!(md.contains("\n\n## --- Part Two ---\n\n"));
+        assert!(md.ends_with("the total is **`48`**?\n"));
+    }
+
+    #[test]
+    fn examples() {
+        assert_eq!(
+            example(PAGE).as_deref(),
+            Some("3 <crates> & 4\n7  9\n\n12 1\n")
+        );
+        assert_eq!(example("<p>No examples here</p>"), None);
+    }
+
+    #[test]
+    fn unclosed_tags() {
+        assert_eq!(
+            tokenize("<p>1 <"),
+            [Token::Open("p", ""), Token::Text("1 "), Token::Text("<")]
+        );
+        assert_eq!(tokenize("a <é"), [Token::Text("a "), Token::Text("<é")]);
+        assert_eq!(to_markdown("<article><p>x &lt; y</p></article><"), "x < y\n");
+    }
+
+    #[test]
+    fn entities() {
+        assert_eq!(
+            unescape("a &lt;b&gt; &amp;&#39;&#x41; & c"),
+            "a <b> &'A & c"
+        );
+    }
+}
@@ -0,0 +1,86 @@
+//! Errors that solutions and the runner report
+
+use std::any::Any;
+use std::fmt::Display;
+use std::io;
+use std::time::Duration;
+
+/// Why a Puzzle, or one of its parts, didn't produce an answer
+#[derive(Debug)]
+pub enum AocError {
+    Io(io::Error),
+    /// The input didn't have the expected format. Lines and columns start at 1
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input was read fine, but has no answer
+    Unsolvable(String),
+    Timeout(Duration),
+    /// A solution panicked, with the panic's message
+    Panic(String),
+}
+
+impl AocError {
+    /// Creates a Parse error at @line, @column (starting at 1)
+    pub fn parse(line: usize, column: usize, message: impl Display) -> Self {
+        AocError::Parse {
+            line,
+            column,
+            message: message.to_string(),
+        }
+    }
+
+    pub fn unsolvable(message: impl Display) -> Self {
+        AocError::Unsolvable(message.to_string())
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::Io(e) => write!(f, "{}", e),
+            AocError::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "parse error at {}:{}: {}", line, column, message),
+            AocError::Unsolvable(msg) => write!(f, "unsolvable: {}", msg),
+            AocError::Timeout(t) => write!(f, "timed out after {:?}", t),
+            AocError::Panic(msg) => write!(f, "panicked: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(value: io::Error) -> Self {
+        AocError::Io(value)
+    }
+}
+
+/// Creates a Panic error from the @payload of a caught panic, and the @location
+/// it happened at if it's known
+pub(crate) fn from_panic(payload: &(dyn Any + Send), location: Option<String>) -> AocError {
+    let message = if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        String::from("unknown panic")
+    };
+
+    match location {
+        Some(location) => AocError::Panic(format!("{} at {}", message, location)),
+        None => AocError::Panic(message),
+    }
+}
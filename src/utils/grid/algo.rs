@@ -1,6 +1,14 @@
 use super::direction::GridDirection;
-use crate::{coord::Coord, grid::in_bounds};
-use std::{collections::BinaryHeap, fmt::Debug};
+use crate::{
+    coord::Coord,
+    grid::{Connectivity, Grid, GridLike, in_bounds, region::Region},
+};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    fmt::Debug,
+    hash::Hash,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct HeapElem {
@@ -35,23 +43,29 @@ impl PartialOrd for HeapElem {
 /// @func is a function that takes the grid, the current node, the cost of reaching the
 /// current node, and the next node and retuns the cost of current -> next
 #[allow(unused)]
-pub fn djikstra<T, F>(grid: &[Vec<T>], start: Coord, end: Coord, func: F) -> Option<Vec<Coord>>
+pub fn djikstra<G, F>(grid: &G, start: Coord, end: Coord, func: F) -> Option<Vec<Coord>>
 where
-    T: Copy + Clone + Ord + Debug,
-    F: Fn(&[Vec<T>], Coord, usize, Coord) -> usize,
+    G: GridLike + ?Sized,
+    G::Item: Debug,
+    F: Fn(&G, Coord, usize, Coord) -> usize,
 {
     use GridDirection::*;
 
-    if grid.is_empty() || !in_bounds(grid, start) || !in_bounds(grid, end) {
+    if !in_bounds(grid, start) || !in_bounds(grid, end) {
         return None;
     }
 
+    // indexed by x * width + y, like Grid
+    let width = grid.width();
+    let idx = |c: Coord| {
+        let (x, y) = c.as_unsigned().unwrap();
+        x * width + y
+    };
+
     let mut queue = BinaryHeap::new();
-    let mut dist =
-        vec![vec![(usize::MAX, Coord::new(i64::MAX, i64::MAX)); grid[0].len()]; grid.len()];
+    let mut dist = vec![(usize::MAX, Coord::new(i64::MAX, i64::MAX)); width * grid.height()];
 
-    let (sx, sy) = start.as_unsigned().unwrap();
-    dist[sx][sy] = (0, Coord::new(0, 0));
+    dist[idx(start)] = (0, Coord::new(0, 0));
 
     queue.push(HeapElem::new(0, start));
     while let Some(he) = queue.pop() {
@@ -68,12 +82,10 @@ where
                 continue;
             }
 
-            let (x, y) = curr.as_unsigned().unwrap();
-            let (nx, ny) = next.as_unsigned().unwrap();
-            let alt = func(grid, curr, dist[x][y].0, next);
+            let alt = func(grid, curr, dist[idx(curr)].0, next);
 
-            if alt < dist[nx][ny].0 {
-                dist[nx][ny] = (alt, curr);
+            if alt < dist[idx(next)].0 {
+                dist[idx(next)] = (alt, curr);
                 queue.push(HeapElem::new(alt, next));
             }
         }
@@ -82,14 +94,12 @@ where
     let mut path = Vec::new();
     path.push(end);
 
-    let (ex, ey) = end.as_unsigned().unwrap();
-    let mut prev = dist[ex][ey].1;
+    let mut prev = dist[idx(end)].1;
 
     while prev != start {
         if in_bounds(grid, prev) {
             path.push(prev);
-            let (px, py) = prev.as_unsigned().unwrap();
-            prev = dist[px][py].1;
+            prev = dist[idx(prev)].1;
         } else {
             return None;
         }
@@ -100,3 +110,362 @@ where
 
     Some(path)
 }
+
+/// Gets the number of steps from @start to every cell of @grid, moving up, down,
+/// left and right. Cells that can't be reached are None
+///
+/// @passable is given the cell being left and the cell being entered, and returns
+/// whether that step can be taken
+pub fn bfs_distances<T, F>(grid: &Grid<T>, start: Coord, passable: F) -> Grid<Option<usize>>
+where
+    F: Fn(&T, &T) -> bool,
+{
+    let mut dist = Grid::new(grid.width(), grid.height(), None);
+    let mut queue = VecDeque::new();
+
+    if grid.in_bounds(start) {
+        dist[start] = Some(0);
+        queue.push_back(start);
+    }
+
+    while let Some(curr) = queue.pop_front() {
+        let steps = dist[curr].unwrap();
+
+        for (next, cell) in grid.neighbors(curr, Connectivity::Four) {
+            if dist[next].is_none() && passable(&grid[curr], cell) {
+                dist[next] = Some(steps + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    dist
+}
+
+/// Gets every cell in the region of @grid containing @start. Neighbouring cells are
+/// in the same region if @same_region returns true for them
+pub fn flood_fill<T, F>(grid: &Grid<T>, start: Coord, same_region: F) -> Region
+where
+    F: Fn(&T, &T) -> bool,
+{
+    let mut seen = Grid::new(grid.width(), grid.height(), false);
+
+    Region::new(fill(grid, start, &same_region, &mut seen))
+}
+
+fn fill<T, F>(grid: &Grid<T>, start: Coord, same_region: &F, seen: &mut Grid<bool>) -> Vec<Coord>
+where
+    F: Fn(&T, &T) -> bool,
+{
+    let mut region = Vec::new();
+    let mut to_visit = Vec::new();
+
+    if grid.in_bounds(start) {
+        seen[start] = true;
+        to_visit.push(start);
+    }
+
+    while let Some(curr) = to_visit.pop() {
+        region.push(curr);
+
+        for (next, cell) in grid.neighbors(curr, Connectivity::Four) {
+            if !seen[next] && same_region(&grid[curr], cell) {
+                seen[next] = true;
+                to_visit.push(next);
+            }
+        }
+    }
+
+    region
+}
+
+/// Regions of a grid, found by connected_components
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    /// The index in regions of the region each cell is in
+    pub labels: Grid<usize>,
+    pub regions: Vec<Region>,
+}
+
+/// Splits @grid into regions, where neighbouring cells are in the same region if
+/// @same_region returns true for them
+pub fn connected_components<T, F>(grid: &Grid<T>, same_region: F) -> Components
+where
+    F: Fn(&T, &T) -> bool,
+{
+    let mut seen = Grid::new(grid.width(), grid.height(), false);
+    let mut labels = Grid::new(grid.width(), grid.height(), 0);
+    let mut regions = Vec::new();
+
+    for coord in grid.coords() {
+        if seen[coord] {
+            continue;
+        }
+
+        let region = fill(grid, coord, &same_region, &mut seen);
+        for c in &region {
+            labels[*c] = regions.len();
+        }
+
+        regions.push(Region::new(region));
+    }
+
+    Components { labels, regions }
+}
+
+/// States reached by a search, along with the cheapest way to reach them
+struct Explored<S> {
+    /// Each state, its cost, and the index of the state it was reached from
+    states: Vec<(S, usize, Option<usize>)>,
+    index: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash> Explored<S> {
+    /// Runs djikstra from @starts until @goal returns true for a state, returning
+    /// that state's index
+    fn run<I, F, G>(
+        starts: impl IntoIterator<Item = S>,
+        mut successors: F,
+        mut goal: G,
+    ) -> (Self, Option<usize>)
+    where
+        I: IntoIterator<Item = (S, usize)>,
+        F: FnMut(&S) -> I,
+        G: FnMut(&S) -> bool,
+    {
+        let mut explored = Explored {
+            states: Vec::new(),
+            index: HashMap::new(),
+        };
+        let mut queue = BinaryHeap::new();
+
+        for s in starts {
+            explored.visit(s, 0, None, &mut queue);
+        }
+
+        while let Some(Reverse((cost, i))) = queue.pop() {
+            // a cheaper way here was found after this one was queued
+            if cost > explored.states[i].1 {
+                continue;
+            }
+
+            let state = explored.states[i].0.clone();
+            if goal(&state) {
+                return (explored, Some(i));
+            }
+
+            for (next, step) in successors(&state) {
+                explored.visit(next, cost + step, Some(i), &mut queue);
+            }
+        }
+
+        (explored, None)
+    }
+
+    fn visit(
+        &mut self,
+        state: S,
+        cost: usize,
+        prev: Option<usize>,
+        queue: &mut BinaryHeap<Reverse<(usize, usize)>>,
+    ) {
+        let i = match self.index.get(&state) {
+            Some(&i) if self.states[i].1 <= cost => return,
+            Some(&i) => {
+                self.states[i].1 = cost;
+                self.states[i].2 = prev;
+                i
+            }
+            None => {
+                self.index.insert(state.clone(), self.states.len());
+                self.states.push((state, cost, prev));
+                self.states.len() - 1
+            }
+        };
+
+        queue.push(Reverse((cost, i)));
+    }
+
+    fn path(&self, mut i: usize) -> Vec<S> {
+        let mut path = vec![self.states[i].0.clone()];
+
+        while let Some(prev) = self.states[i].2 {
+            path.push(self.states[prev].0.clone());
+            i = prev;
+        }
+
+        path.reverse();
+        path
+    }
+}
+
+/// Finds the cheapest path from any of @starts to a state @goal returns true for,
+/// returning its cost and every state along it
+///
+/// States can hold anything besides a position, like the direction being faced or
+/// how many steps were taken in a straight line. @successors gets the states that
+/// can be reached from a state, along with the cost of moving to each of them, so
+/// rules like turn costs or a minimum run before turning go there
+pub fn search<S, I, F, G>(
+    starts: impl IntoIterator<Item = S>,
+    successors: F,
+    goal: G,
+) -> Option<(usize, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+    F: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    let (explored, end) = Explored::run(starts, successors, goal);
+    let end = end?;
+
+    Some((explored.states[end].1, explored.path(end)))
+}
+
+/// Gets the cost of the cheapest path from any of @starts to every state that can
+/// be reached. @successors works like it does for search
+pub fn search_costs<S, I, F>(
+    starts: impl IntoIterator<Item = S>,
+    successors: F,
+) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+    F: FnMut(&S) -> I,
+{
+    let (explored, _) = Explored::run(starts, successors, |_| false);
+
+    explored
+        .states
+        .into_iter()
+        .map(|(state, cost, _)| (state, cost))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "S.#.\n#...\n..#E\n#.##";
+
+    #[test]
+    fn distances() {
+        let grid = Grid::parse(MAZE, |c| c).unwrap();
+        let start = grid.find(&'S').unwrap();
+        let dist = bfs_distances(&grid, start, |_, next| *next != '#');
+
+        assert_eq!(dist[grid.find(&'E').unwrap()], Some(5));
+        assert_eq!(dist[Coord::new(0, 3)], Some(5));
+        assert_eq!(dist[Coord::new(3, 1)], Some(4));
+        assert_eq!(dist[Coord::new(0, 2)], None);
+
+        // only going uphill by at most 1
+        let hills = Grid::parse("0123\n9954\n8765", |c| c.to_digit(10).unwrap()).unwrap();
+        let dist = bfs_distances(&hills, Coord::new(0, 0), |a, b| *b <= a + 1);
+        assert_eq!(dist[Coord::new(2, 0)], Some(8));
+        assert_eq!(dist[Coord::new(1, 1)], Some(10));
+    }
+
+    #[test]
+    fn regions() {
+        let grid = Grid::parse("AAB\nBAB\nBBA", |c| c).unwrap();
+
+        let region = flood_fill(&grid, Coord::new(0, 0), |a, b| a == b);
+        assert_eq!(
+            region,
+            Region::new([Coord::new(1, 1), Coord::new(0, 1), Coord::new(0, 0)])
+        );
+
+        let components = connected_components(&grid, |a, b| a == b);
+        let sizes: Vec<usize> = components.regions.iter().map(|r| r.area()).collect();
+        assert_eq!(sizes, [3, 2, 3, 1]);
+        assert_eq!(components.labels[Coord::new(2, 2)], 3);
+        assert_eq!(components.labels.to_string(), "001\n201\n223");
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    struct Cart {
+        pos: Coord,
+        dir: GridDirection,
+        run: usize,
+    }
+
+    /// Carts have to go at least @min steps before turning, and at most @max
+    fn carts(grid: &Grid<u32>, cart: &Cart, min: usize, max: usize) -> Vec<(Cart, usize)> {
+        [cart.dir, cart.dir.rotate_left(), cart.dir.rotate_right()]
+            .into_iter()
+            .filter(|d| {
+                if *d == cart.dir {
+                    cart.run < max
+                } else {
+                    cart.run >= min
+                }
+            })
+            .filter_map(|dir| {
+                let pos = cart.pos + dir.into();
+                let run = if dir == cart.dir { cart.run + 1 } else { 1 };
+
+                grid.get(pos)
+                    .map(|cost| (Cart { pos, dir, run }, *cost as usize))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn stateful_search() {
+        let grid = Grid::parse(
+            "111111111111\n999999999991\n999999999991\n999999999991\n999999999991",
+            |c| c.to_digit(10).unwrap(),
+        )
+        .unwrap();
+        let end = Coord::new(4, 11);
+        let start = Cart {
+            pos: Coord::new(0, 0),
+            dir: GridDirection::Right,
+            run: 0,
+        };
+
+        let (cost, path) = search([start], |c| carts(&grid, c, 0, 3), |c| c.pos == end).unwrap();
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last().unwrap().pos, end);
+        assert!(path.iter().all(|c| c.run <= 3));
+        assert_eq!(
+            cost,
+            path[1..]
+                .iter()
+                .map(|c| grid[c.pos] as usize)
+                .sum::<usize>()
+        );
+
+        let (cost, path) = search(
+            [start],
+            |c| carts(&grid, c, 4, 10),
+            |c| c.pos == end && c.run >= 4,
+        )
+        .unwrap();
+        assert_eq!(cost, 71);
+        assert!(
+            path.windows(2)
+                .all(|w| w[0].dir == w[1].dir || w[0].run >= 4)
+        );
+
+        let costs = search_costs([start], |c| carts(&grid, c, 4, 10));
+        assert_eq!(
+            costs
+                .keys()
+                .filter(|c| c.pos == end && c.run >= 4)
+                .map(|c| costs[c])
+                .min(),
+            Some(71)
+        );
+        assert!(
+            search(
+                [start],
+                |c| carts(&grid, c, 4, 10),
+                |c| c.pos == Coord::new(9, 9)
+            )
+            .is_none()
+        );
+    }
+}
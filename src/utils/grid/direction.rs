@@ -10,7 +10,7 @@ pub const DIRECTIONS: [GridDirection; 4] = [
     GridDirection::Right,
 ];
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum GridDirection {
     Up,
     Down,
@@ -1,20 +1,468 @@
 pub mod algo;
 pub mod direction;
+pub mod region;
+
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
 
 use super::coord::Coord;
+use super::error::AocError;
+
+/// Grids that can be indexed by Coord, where x is the row and y is the column.
+/// Implemented by Grid and by the Vec<Vec<T>> grids that came before it
+pub trait GridLike {
+    type Item;
+
+    /// Gets the number of rows
+    fn height(&self) -> usize;
+
+    /// Gets the number of columns. For ragged grids, this is the length of the
+    /// longest row
+    fn width(&self) -> usize;
+
+    /// Gets the cell at @coord, or None if it's out of bounds
+    fn cell(&self, coord: Coord) -> Option<&Self::Item>;
+}
+
+impl<T> GridLike for [Vec<T>] {
+    type Item = T;
+
+    fn height(&self) -> usize {
+        self.len()
+    }
+
+    fn width(&self) -> usize {
+        self.iter().map(|row| row.len()).max().unwrap_or(0)
+    }
+
+    fn cell(&self, coord: Coord) -> Option<&T> {
+        let (x, y) = coord.as_unsigned()?;
+        self.get(x)?.get(y)
+    }
+}
+
+impl<T> GridLike for Vec<Vec<T>> {
+    type Item = T;
+
+    fn height(&self) -> usize {
+        self.as_slice().height()
+    }
+
+    fn width(&self) -> usize {
+        self.as_slice().width()
+    }
+
+    fn cell(&self, coord: Coord) -> Option<&T> {
+        self.as_slice().cell(coord)
+    }
+}
+
+/// Checks whether @coord is inside of @grid
+pub fn in_bounds<G: GridLike + ?Sized>(grid: &G, coord: Coord) -> bool {
+    grid.cell(coord).is_some()
+}
+
+/// Which cells count as the neighbours of a cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Up, down, left and right
+    Four,
+    /// Four, plus the diagonals
+    Eight,
+}
+
+const OFFSETS: [Coord; 8] = [
+    Coord { x: -1, y: 0 },
+    Coord { x: 1, y: 0 },
+    Coord { x: 0, y: -1 },
+    Coord { x: 0, y: 1 },
+    Coord { x: -1, y: -1 },
+    Coord { x: -1, y: 1 },
+    Coord { x: 1, y: -1 },
+    Coord { x: 1, y: 1 },
+];
+
+impl Connectivity {
+    /// Gets the offsets from a cell to its neighbours, orthogonal ones first
+    pub fn offsets(&self) -> &'static [Coord] {
+        match self {
+            Connectivity::Four => &OFFSETS[..4],
+            Connectivity::Eight => &OFFSETS,
+        }
+    }
+}
+
+/// Wraps @coord around the edges of a @width by @height grid, so that it's
+/// always in bounds
+pub fn wrap(coord: Coord, width: usize, height: usize) -> Coord {
+    Coord::new(
+        coord.x.rem_euclid(height as i64),
+        coord.y.rem_euclid(width as i64),
+    )
+}
+
+/// Rectangular grid stored in a single Vec, one row after another. Like the rest
+/// of grid, cells are indexed by Coord with x as the row and y as the column
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a @width by @height Grid with every cell set to @value
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Creates a Grid from @cells, which holds rows of @width cells one after another
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells can't be split into rows of {}",
+            cells.len(),
+            width
+        );
+
+        Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    /// Parses @input as a Grid with one row per line, turning each character into
+    /// a cell with @f. Every line has to be the same length
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Result<Self, AocError> {
+        Grid::try_parse(input, |c| Some(f(c)))
+    }
+
+    /// Like parse, but characters that @f returns None for are parse errors
+    pub fn try_parse(input: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self, AocError> {
+        let mut width = None;
+        let mut cells = Vec::new();
+        let mut height = 0;
+
+        for (i, line) in input.lines().enumerate() {
+            let len = cells.len();
+            for (j, c) in line.chars().enumerate() {
+                let cell = f(c)
+                    .ok_or_else(|| AocError::parse(i + 1, j + 1, format!("unexpected '{}'", c)))?;
+                cells.push(cell);
+            }
+
+            let row = cells.len() - len;
+            match width {
+                Some(w) if w != row => {
+                    return Err(AocError::parse(
+                        i + 1,
+                        row.min(w) + 1,
+                        format!("expected {} columns, found {}", w, row),
+                    ));
+                }
+                _ => width = Some(row),
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, coord: Coord) -> bool {
+        self.index_of(coord).is_some()
+    }
+
+    fn index_of(&self, coord: Coord) -> Option<usize> {
+        let (x, y) = coord.as_unsigned()?;
+
+        if x < self.height && y < self.width {
+            Some(x * self.width + y)
+        } else {
+            None
+        }
+    }
+
+    fn coord_of(&self, i: usize) -> Coord {
+        Coord::new((i / self.width) as i64, (i % self.width) as i64)
+    }
+
+    /// Gets the cell at @coord, or None if it's out of bounds
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.index_of(coord).map(|i| &self.cells[i])
+    }
+
+    /// Gets the cell at @coord mutably, or None if it's out of bounds
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.index_of(coord).map(|i| &mut self.cells[i])
+    }
+
+    /// Gets an iterator over the rows of this Grid, from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on 0, and an empty Grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Gets an iterator over the cells in column @y, from top to bottom
+    pub fn column(&self, y: usize) -> impl Iterator<Item = &T> {
+        let cells = if y < self.width {
+            &self.cells[y..]
+        } else {
+            &[]
+        };
+
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    /// Gets an iterator over the columns of this Grid, from left to right
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|y| self.column(y))
+    }
+
+    /// Gets an iterator over every cell and its Coord, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.coord_of(i), cell))
+    }
+
+    /// Gets an iterator over the Coord of every cell, row by row
+    pub fn coords(&self) -> impl Iterator<Item = Coord> + use<T> {
+        let width = self.width as i64;
+
+        (0..self.height as i64).flat_map(move |x| (0..width).map(move |y| Coord::new(x, y)))
+    }
 
-pub fn in_bounds<T>(grid: &[Vec<T>], coord: Coord) -> bool {
-    let (x, y): (i64, i64) = coord.into();
+    /// Creates a Grid of the same size by applying @f to every cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Finds the first cell equal to @value, like the start marker of a maze
+    pub fn find(&self, value: &T) -> Option<Coord>
+    where
+        T: PartialEq,
+    {
+        self.cells
+            .iter()
+            .position(|cell| cell == value)
+            .map(|i| self.coord_of(i))
+    }
+
+    /// Gets the Coord of every cell equal to @value, row by row
+    pub fn positions<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Coord> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(coord, _)| coord)
+    }
+
+    /// Gets the neighbours of @coord that are in bounds, along with their cells
+    pub fn neighbors(
+        &self,
+        coord: Coord,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = (Coord, &T)> {
+        connectivity.offsets().iter().filter_map(move |&offset| {
+            let next = coord + offset;
+            self.get(next).map(|cell| (next, cell))
+        })
+    }
 
-    if x < 0 || y < 0 {
-        return false;
+    /// Wraps @coord around the edges of this Grid, as if it were a torus
+    pub fn wrap(&self, coord: Coord) -> Coord {
+        wrap(coord, self.width, self.height)
     }
 
-    let (x, y) = coord.as_unsigned().unwrap();
+    /// Gets the neighbours of @coord, wrapping around the edges of this Grid.
+    /// Every cell has a full set of neighbours, unless the Grid is empty
+    pub fn neighbors_wrapping(
+        &self,
+        coord: Coord,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = (Coord, &T)> {
+        let offsets = if self.cells.is_empty() {
+            &[]
+        } else {
+            connectivity.offsets()
+        };
 
-    if grid.is_empty() || x >= grid.len() {
-        return false;
+        offsets.iter().map(move |&offset| {
+            let next = self.wrap(coord + offset);
+            (next, &self[next])
+        })
     }
+}
 
-    !grid[x].is_empty() && y < grid[x].len()
+impl<T> GridLike for Grid<T> {
+    type Item = T;
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn cell(&self, coord: Coord) -> Option<&T> {
+        self.get(coord)
+    }
+}
+
+/// Panics if @coord is out of bounds
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &T {
+        match self.index_of(coord) {
+            Some(i) => &self.cells[i],
+            None => panic!(
+                "{} is out of bounds of a {}x{} grid",
+                coord, self.width, self.height
+            ),
+        }
+    }
+}
+
+/// Panics if @coord is out of bounds
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        match self.index_of(coord) {
+            Some(i) => &mut self.cells[i],
+            None => panic!(
+                "{} is out of bounds of a {}x{} grid",
+                coord, self.width, self.height
+            ),
+        }
+    }
+}
+
+/// Prints each row on its own line, with nothing between cells
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "#S.#\n#..#\n##E#\n";
+
+    #[test]
+    fn parse_and_index() {
+        let mut grid = Grid::parse(MAZE, |c| c).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (4, 3));
+        assert_eq!(grid[Coord::new(2, 2)], 'E');
+        assert_eq!(grid.get(Coord::new(3, 0)), None);
+        assert_eq!(grid.get(Coord::new(0, -1)), None);
+        assert_eq!(grid.find(&'S'), Some(Coord::new(0, 1)));
+        assert_eq!(grid.positions(&'.').count(), 3);
+
+        *grid.get_mut(Coord::new(0, 1)).unwrap() = '.';
+        assert_eq!(grid.find(&'S'), None);
+        assert_eq!(grid.to_string(), "#..#\n#..#\n##E#");
+
+        assert!(Grid::parse("..\n.\n", |c| c).is_err());
+        assert!(matches!(
+            Grid::try_parse("12\n3x", |c| c.to_digit(10)),
+            Err(AocError::Parse {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::try_parse("123\n456", |c| c.to_digit(10)).unwrap();
+        let rows: Vec<&[u32]> = grid.rows().collect();
+        let columns: Vec<Vec<u32>> = grid.columns().map(|c| c.copied().collect()).collect();
+
+        assert_eq!(rows, [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(columns, [[1, 4], [2, 5], [3, 6]]);
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.coords().last(), Some(Coord::new(1, 2)));
+        assert_eq!(grid.map(|n| n * 2)[Coord::new(1, 0)], 8);
+    }
+
+    #[test]
+    fn bounds() {
+        let ragged = vec![vec![1, 2, 3], vec![4]];
+        let grid = Grid::from_vec(2, vec![1, 2, 3, 4]);
+
+        assert!(in_bounds(&ragged, Coord::new(0, 2)));
+        assert!(!in_bounds(&ragged, Coord::new(1, 1)));
+        assert!(in_bounds(&grid, Coord::new(1, 1)));
+        assert!(!in_bounds(&grid, Coord::new(2, 0)));
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = Grid::parse("abc\ndef\nghi", |c| c).unwrap();
+        fn cells<'a>(it: impl Iterator<Item = (Coord, &'a char)>) -> String {
+            let mut cells: Vec<char> = it.map(|(_, c)| *c).collect();
+            cells.sort();
+            cells.into_iter().collect()
+        }
+
+        let origin = Coord::new(0, 0);
+        assert_eq!(cells(grid.neighbors(origin, Connectivity::Four)), "bd");
+        assert_eq!(cells(grid.neighbors(origin, Connectivity::Eight)), "bde");
+        assert_eq!(
+            cells(grid.neighbors(Coord::new(1, 1), Connectivity::Eight)),
+            "abcdfghi"
+        );
+        assert_eq!(
+            cells(grid.neighbors_wrapping(origin, Connectivity::Four)),
+            "bcdg"
+        );
+        assert_eq!(
+            cells(grid.neighbors_wrapping(origin, Connectivity::Eight)),
+            "bcdefghi"
+        );
+
+        assert_eq!(wrap(Coord::new(-1, 7), 5, 3), Coord::new(2, 2));
+    }
 }
@@ -0,0 +1,230 @@
+use std::collections::HashSet;
+
+use super::algo::connected_components;
+use super::{Connectivity, Grid};
+use crate::coord::Coord;
+
+/// Orthogonal neighbours that share a corner, along with the diagonal between them
+const CORNERS: [(Coord, Coord, Coord); 4] = [
+    (
+        Coord { x: -1, y: 0 },
+        Coord { x: 0, y: -1 },
+        Coord { x: -1, y: -1 },
+    ),
+    (
+        Coord { x: -1, y: 0 },
+        Coord { x: 0, y: 1 },
+        Coord { x: -1, y: 1 },
+    ),
+    (
+        Coord { x: 1, y: 0 },
+        Coord { x: 0, y: -1 },
+        Coord { x: 1, y: -1 },
+    ),
+    (
+        Coord { x: 1, y: 0 },
+        Coord { x: 0, y: 1 },
+        Coord { x: 1, y: 1 },
+    ),
+];
+
+/// Group of cells in a grid, like a garden plot or a lake. Cells are connected
+/// through their up, down, left and right neighbours
+#[derive(Debug, Clone)]
+pub struct Region {
+    /// In the order they were added
+    cells: Vec<Coord>,
+    set: HashSet<Coord>,
+}
+
+impl Region {
+    /// Creates a Region out of @cells, ignoring duplicates
+    pub fn new(cells: impl IntoIterator<Item = Coord>) -> Self {
+        let mut region = Region {
+            cells: Vec::new(),
+            set: HashSet::new(),
+        };
+
+        for c in cells {
+            if region.set.insert(c) {
+                region.cells.push(c);
+            }
+        }
+
+        region
+    }
+
+    pub fn cells(&self) -> &[Coord] {
+        &self.cells
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        self.set.contains(&coord)
+    }
+
+    /// Gets the number of cells in this Region
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Gets the number of cell edges between this Region and the cells around it
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|c| self.outside_edges(*c).count())
+            .sum()
+    }
+
+    /// Gets the number of straight sides this Region's outline has, including the
+    /// outlines of holes. A shape has as many sides as it has corners
+    pub fn sides(&self) -> usize {
+        let mut corners = 0;
+
+        for c in &self.cells {
+            for (a, b, diagonal) in CORNERS {
+                let (a, b) = (self.contains(*c + a), self.contains(*c + b));
+
+                // convex corners stick out, concave ones are filled on both sides
+                if (!a && !b) || (a && b && !self.contains(*c + diagonal)) {
+                    corners += 1;
+                }
+            }
+        }
+
+        corners
+    }
+
+    /// Gets the smallest and largest x and y of this Region's cells, as the top
+    /// left and bottom right corners of a box around it. None if it's empty
+    pub fn bounding_box(&self) -> Option<(Coord, Coord)> {
+        let first = *self.cells.first()?;
+
+        Some(self.cells.iter().fold((first, first), |(min, max), c| {
+            (
+                Coord::new(min.x.min(c.x), min.y.min(c.y)),
+                Coord::new(max.x.max(c.x), max.y.max(c.y)),
+            )
+        }))
+    }
+
+    /// Gets the cells that have a neighbour outside of this Region
+    pub fn boundary(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.cells
+            .iter()
+            .copied()
+            .filter(|c| self.outside_edges(*c).next().is_some())
+    }
+
+    /// Gets the groups of cells that are surrounded by this Region, but aren't
+    /// part of it
+    pub fn holes(&self) -> Vec<Region> {
+        let Some((min, max)) = self.bounding_box() else {
+            return Vec::new();
+        };
+
+        // a ring of outside cells around the box connects everything outside
+        let offset = min - Coord::new(1, 1);
+        let mut grid = Grid::new(
+            (max.y - min.y + 3) as usize,
+            (max.x - min.x + 3) as usize,
+            false,
+        );
+        for c in &self.cells {
+            grid[*c - offset] = true;
+        }
+
+        let components = connected_components(&grid, |a, b| a == b);
+        let outside = components.labels[Coord::new(0, 0)];
+
+        components
+            .regions
+            .into_iter()
+            .enumerate()
+            .filter(|(i, r)| *i != outside && !grid[r.cells[0]])
+            .map(|(_, r)| Region::new(r.cells.into_iter().map(|c| c + offset)))
+            .collect()
+    }
+
+    fn outside_edges(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Connectivity::Four
+            .offsets()
+            .iter()
+            .map(move |d| coord + *d)
+            .filter(|c| !self.contains(*c))
+    }
+}
+
+/// Regions are equal if they have the same cells, in any order
+impl PartialEq for Region {
+    fn eq(&self, other: &Self) -> bool {
+        self.set == other.set
+    }
+}
+
+impl Eq for Region {}
+
+impl FromIterator<Coord> for Region {
+    fn from_iter<I: IntoIterator<Item = Coord>>(iter: I) -> Self {
+        Region::new(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn regions(input: &str) -> Vec<(char, Region)> {
+        let grid = Grid::parse(input, |c| c).unwrap();
+
+        connected_components(&grid, |a, b| a == b)
+            .regions
+            .into_iter()
+            .map(|r| (grid[r.cells()[0]], r))
+            .collect()
+    }
+
+    #[test]
+    fn measurements() {
+        let garden = regions("AAAA\nBBCD\nBBCC\nEEEC");
+        let measured: Vec<(char, usize, usize, usize)> = garden
+            .iter()
+            .map(|(c, r)| (*c, r.area(), r.perimeter(), r.sides()))
+            .collect();
+
+        assert_eq!(
+            measured,
+            [
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4),
+            ]
+        );
+        assert_eq!(
+            garden[2].1.bounding_box(),
+            Some((Coord::new(1, 2), Coord::new(3, 3)))
+        );
+
+        let e = &regions("EEEEE\nEXXXX\nEEEEE\nEXXXX\nEEEEE")[0].1;
+        assert_eq!((e.area(), e.sides()), (17, 12));
+    }
+
+    #[test]
+    fn holes_and_boundary() {
+        let garden = regions("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO");
+        let o = &garden[0].1;
+
+        assert_eq!((o.area(), o.perimeter(), o.sides()), (21, 36, 20));
+        assert_eq!(o.holes().len(), 4);
+        assert_eq!(o.boundary().count(), 20);
+        assert!(garden[1].1.holes().is_empty());
+
+        // the gap in the ring lets the middle out
+        let ring = regions("###\n#.#\n###\n").remove(0).1;
+        let open = regions("#.#\n#.#\n###\n").remove(0).1;
+        assert_eq!(ring.holes(), [Region::new([Coord::new(1, 1)])]);
+        assert!(open.holes().is_empty());
+        assert_eq!(ring.boundary().count(), 8);
+    }
+}
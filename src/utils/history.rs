@@ -0,0 +1,461 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::bench::{BenchResult, Stats};
+use crate::solution::{Answer, PartResult, RunResult, as_nanos};
+
+/// File timings are appended to, one JSON record per line
+pub const HISTORY_FILE: &str = "history/timings.jsonl";
+
+/// What produced a Record
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Source {
+    Run,
+    Bench,
+}
+
+/// A single timing of one step (parse, part1 or part2) of a day
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub commit: String,
+    /// Whether there were uncommitted changes when this Record was taken
+    pub dirty: bool,
+    pub timestamp: u64,
+    pub source: Source,
+    pub year: u32,
+    pub day: usize,
+    pub step: String,
+    pub time_ns: u64,
+    /// The answer a part gave, for Records of runs. Unsolved parts are stored as
+    /// null, so that they can be told apart from Records without an answer
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "some_answer"
+    )]
+    pub answer: Option<Answer>,
+}
+
+fn some_answer<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Answer>, D::Error> {
+    Answer::deserialize(deserializer).map(Some)
+}
+
+/// The commit that is currently checked out
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Revision {
+    pub commit: String,
+    pub dirty: bool,
+}
+
+impl Revision {
+    /// Gets the checked out commit and whether the working tree has changes. If this
+    /// isn't a git repository, the commit is "unknown"
+    pub fn current() -> Self {
+        let commit = resolve("HEAD").unwrap_or_else(|| String::from("unknown"));
+        let dirty = git(&["status", "--porcelain", "--untracked-files=no"])
+            .map(|s| !s.is_empty())
+            .unwrap_or(false);
+
+        Revision { commit, dirty }
+    }
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+
+    if output.status.success() {
+        Some(String::from_utf8_lossy(&output.stdout).trim().to_owned())
+    } else {
+        None
+    }
+}
+
+/// Resolves @rev (a branch, tag, commit, ...) to a full commit hash
+pub fn resolve(rev: &str) -> Option<String> {
+    git(&[
+        "rev-parse",
+        "--verify",
+        "--quiet",
+        &format!("{}^{{commit}}", rev),
+    ])
+}
+
+fn to_nanos(d: Duration) -> u64 {
+    d.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+impl Record {
+    fn new(
+        rev: &Revision,
+        source: Source,
+        (year, day): (u32, usize),
+        step: &str,
+        time: Duration,
+        answer: Option<&Answer>,
+    ) -> Self {
+        Record {
+            commit: rev.commit.clone(),
+            dirty: rev.dirty,
+            timestamp: now(),
+            source,
+            year,
+            day,
+            step: step.to_owned(),
+            time_ns: to_nanos(time),
+            answer: answer.cloned(),
+        }
+    }
+
+    /// Creates a Record for each step of @result, along with the answers of its parts
+    pub fn from_run(rev: &Revision, result: &RunResult) -> Vec<Self> {
+        let key = (result.year, result.day);
+
+        vec![
+            Record::new(rev, Source::Run, key, "parse", result.parse, None),
+            Record::new(
+                rev,
+                Source::Run,
+                key,
+                "part1",
+                result.part1.time,
+                Some(&result.part1.answer),
+            ),
+            Record::new(
+                rev,
+                Source::Run,
+                key,
+                "part2",
+                result.part2.time,
+                Some(&result.part2.answer),
+            ),
+        ]
+    }
+
+    /// Creates a Record for each step of @result, using the median time
+    pub fn from_bench(rev: &Revision, result: &BenchResult) -> Vec<Self> {
+        result
+            .steps()
+            .into_iter()
+            .map(|(step, stats)| {
+                Record::new(
+                    rev,
+                    Source::Bench,
+                    (result.year, result.day),
+                    step,
+                    stats.median,
+                    None,
+                )
+            })
+            .collect()
+    }
+}
+
+/// Append-only log of timing Records
+pub struct History {
+    path: PathBuf,
+}
+
+impl History {
+    pub fn new(path: &Path) -> Self {
+        History {
+            path: path.to_owned(),
+        }
+    }
+
+    /// Appends @records to the end of this History
+    pub fn append(&self, records: &[Record]) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        let mut buf = String::new();
+
+        for r in records {
+            buf.push_str(&serde_json::to_string(r)?);
+            buf.push('\n');
+        }
+
+        file.write_all(buf.as_bytes())
+    }
+
+    /// Gets every Record in this History. Lines that can't be parsed are skipped
+    pub fn load(&self) -> io::Result<Vec<Record>> {
+        let file = match fs::File::open(&self.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+
+        let mut records = Vec::new();
+        for line in BufReader::new(file).lines() {
+            if let Ok(r) = serde_json::from_str(&line?) {
+                records.push(r);
+            }
+        }
+
+        Ok(records)
+    }
+}
+
+/// Rebuilds the last run of @year @day from @records. Returns None if it hasn't
+/// been run since answers started being recorded
+pub fn last_run(records: &[Record], year: u32, day: usize) -> Option<RunResult> {
+    let last = |step: &str| {
+        records
+            .iter()
+            .rev()
+            .find(|r| r.source == Source::Run && r.year == year && r.day == day && r.step == step)
+    };
+    let part = |step: &str| {
+        let r = last(step)?;
+
+        Some(PartResult {
+            answer: r.answer.clone()?,
+            time: Duration::from_nanos(r.time_ns),
+            memory: None,
+            error: None,
+        })
+    };
+
+    Some(RunResult {
+        year,
+        day,
+        parse: Duration::from_nanos(last("parse")?.time_ns),
+        parse_memory: None,
+        part1: part("part1")?,
+        part2: part("part2")?,
+    })
+}
+
+/// Timing of a step compared against the timings recorded for another commit
+#[derive(Debug, Clone, Serialize)]
+pub struct Comparison {
+    pub year: u32,
+    pub day: usize,
+    pub step: &'static str,
+    #[serde(rename = "baseline_ns", serialize_with = "as_nanos")]
+    pub baseline: Duration,
+    pub current: Stats,
+    pub slower: bool,
+    /// Whether the baseline was recorded with uncommitted changes, so it might
+    /// not match @commit
+    pub dirty_baseline: bool,
+}
+
+impl Comparison {
+    /// Relative change in the median time, i.e. 0.5 is 50% slower
+    pub fn change(&self) -> f64 {
+        self.current.median.as_secs_f64() / self.baseline.as_secs_f64().max(1e-9) - 1.0
+    }
+}
+
+/// Compares each step of @results against the Records in @history that were taken
+/// at @commit. Bench Records are used if there are any for a step, otherwise run
+/// Records are used. Records from a clean working tree are preferred over dirty
+/// ones, which are only used when there's nothing else, and are marked as such.
+/// The baseline for a step is the median of its Records.
+///
+/// A step is flagged as slower if its median is more than @threshold (e.g. 0.1 = 10%)
+/// slower than the baseline, and the difference is bigger than two standard
+/// deviations, so that noisy steps aren't flagged
+pub fn compare(
+    history: &[Record],
+    commit: &str,
+    results: &[BenchResult],
+    threshold: f64,
+) -> Vec<Comparison> {
+    let mut comparisons = Vec::new();
+
+    for result in results {
+        for (step, stats) in result.steps() {
+            let matching = |source: Source, dirty: bool| -> Vec<u64> {
+                history
+                    .iter()
+                    .filter(|r| {
+                        r.commit == commit
+                            && r.dirty == dirty
+                            && r.source == source
+                            && r.year == result.year
+                            && r.day == result.day
+                            && r.step == step
+                    })
+                    .map(|r| r.time_ns)
+                    .collect()
+            };
+
+            let found = [false, true].into_iter().find_map(|dirty| {
+                [Source::Bench, Source::Run]
+                    .into_iter()
+                    .map(|source| matching(source, dirty))
+                    .find(|times| !times.is_empty())
+                    .map(|times| (times, dirty))
+            });
+
+            let Some((mut times, dirty_baseline)) = found else {
+                continue;
+            };
+
+            times.sort();
+            let baseline = Duration::from_nanos(times[times.len() / 2]);
+            let limit = baseline
+                .mul_f64(1.0 + threshold)
+                .max(baseline + stats.stddev * 2);
+
+            comparisons.push(Comparison {
+                year: result.year,
+                day: result.day,
+                step,
+                baseline,
+                current: *stats,
+                slower: stats.median > limit,
+                dirty_baseline,
+            });
+        }
+    }
+
+    comparisons
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(median: u64, stddev: u64) -> Stats {
+        Stats {
+            min: Duration::from_micros(median),
+            median: Duration::from_micros(median),
+            mean: Duration::from_micros(median),
+            stddev: Duration::from_micros(stddev),
+        }
+    }
+
+    #[test]
+    fn history_roundtrip() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc-history-{}", std::process::id()))
+            .join("timings.jsonl");
+        let history = History::new(&path);
+        let rev = Revision {
+            commit: String::from("abc"),
+            dirty: false,
+        };
+        let result = BenchResult {
+            year: 2024,
+            day: 1,
+            iterations: 10,
+            parse: stats(1, 0),
+            part1: stats(100, 5),
+            part2: stats(200, 5),
+        };
+
+        history.append(&Record::from_bench(&rev, &result)).unwrap();
+        history.append(&Record::from_bench(&rev, &result)).unwrap();
+
+        let records = history.load().unwrap();
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+        assert_eq!(records.len(), 6);
+        assert_eq!(records[1].step, "part1");
+        assert_eq!(records[1].time_ns, 100_000);
+    }
+
+    #[test]
+    fn run_answers() {
+        let rev = Revision {
+            commit: String::from("abc"),
+            dirty: false,
+        };
+        let part = |answer| PartResult {
+            answer,
+            time: Duration::from_micros(5),
+            memory: None,
+            error: None,
+        };
+        let result = RunResult {
+            year: 2024,
+            day: 17,
+            parse: Duration::from_micros(1),
+            parse_memory: None,
+            part1: part(Answer::from("4,6,3")),
+            part2: part(Answer::Unsolved),
+        };
+
+        let records: Vec<Record> = Record::from_run(&rev, &result)
+            .iter()
+            .map(|r| serde_json::from_str(&serde_json::to_string(r).unwrap()).unwrap())
+            .collect();
+
+        assert_eq!(records[0].answer, None);
+        assert_eq!(records[1].answer, Some(Answer::from("4,6,3")));
+        assert_eq!(records[2].answer, Some(Answer::Unsolved));
+
+        let last = last_run(&records, 2024, 17).unwrap();
+        assert_eq!(last.part1.answer, Answer::from("4,6,3"));
+        assert_eq!(last.part2.time, Duration::from_micros(5));
+        assert!(last_run(&records, 2024, 16).is_none());
+    }
+
+    #[test]
+    fn compare_flags_slower_steps() {
+        let rev = Revision {
+            commit: String::from("abc"),
+            dirty: false,
+        };
+        let old = BenchResult {
+            year: 2024,
+            day: 1,
+            iterations: 10,
+            parse: stats(1, 0),
+            part1: stats(100, 5),
+            part2: stats(200, 5),
+        };
+        let new = BenchResult {
+            parse: stats(1, 0),
+            part1: stats(105, 5),
+            part2: stats(400, 5),
+            ..old.clone()
+        };
+
+        let history = Record::from_bench(&rev, &old);
+        let comparisons = compare(&history, "abc", std::slice::from_ref(&new), 0.1);
+
+        assert_eq!(comparisons.len(), 3);
+        assert!(!comparisons[0].slower);
+        assert!(!comparisons[1].slower);
+        assert!(comparisons[2].slower);
+        assert!((comparisons[2].change() - 1.0).abs() < 1e-9);
+
+        assert!(compare(&history, "def", std::slice::from_ref(&new), 0.1).is_empty());
+        assert!(comparisons.iter().all(|c| !c.dirty_baseline));
+
+        // clean Records win over dirty ones, which are only used as a last resort
+        let dirty = Revision {
+            commit: String::from("abc"),
+            dirty: true,
+        };
+        let mut history = Record::from_bench(&dirty, &new);
+        assert!(compare(&history, "abc", std::slice::from_ref(&new), 0.1)[2].dirty_baseline);
+
+        history.extend(Record::from_bench(&rev, &old));
+        let comparisons = compare(&history, "abc", &[new], 0.1);
+        assert!(comparisons[2].slower);
+        assert!(!comparisons[2].dirty_baseline);
+    }
+}
@@ -0,0 +1,206 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::solution::{Example, Puzzle};
+
+/// Environment variable that overrides the directory inputs are read from
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Finds the root of this project, so that the binary works from any directory.
+/// The root is the closest ancestor of the current directory that has both a
+/// Cargo.toml and an inputs directory. If there isn't one, the directory this
+/// was built from is used, and if that's gone too, the current directory
+pub fn project_root() -> PathBuf {
+    let cwd = env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+
+    if let Some(root) = cwd
+        .ancestors()
+        .find(|dir| dir.join("Cargo.toml").is_file() && dir.join("inputs").is_dir())
+    {
+        return root.to_owned();
+    }
+
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+    if manifest.is_dir() {
+        manifest.to_owned()
+    } else {
+        cwd
+    }
+}
+
+/// Locates the input files of Puzzles. Inputs for a Puzzle are stored in
+/// <dir>/<year>/day<day>.txt, with examples next to them
+#[derive(Debug, Clone)]
+pub struct Inputs {
+    dir: PathBuf,
+}
+
+impl Inputs {
+    pub fn new(dir: &Path) -> Self {
+        Inputs {
+            dir: dir.to_owned(),
+        }
+    }
+
+    /// Gets the input directory from $AOC_INPUT_DIR, or <project root>/inputs
+    /// if it isn't set
+    pub fn locate() -> Self {
+        match env::var_os(INPUT_DIR_VAR) {
+            Some(dir) if !dir.is_empty() => Inputs::new(Path::new(&dir)),
+            _ => Inputs::new(&project_root().join("inputs")),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Gets the directory holding the inputs for @year
+    pub fn year_dir(&self, year: u32) -> PathBuf {
+        self.dir.join(year.to_string())
+    }
+
+    /// Gets the path of the input file for @puzzle
+    pub fn path(&self, puzzle: &Puzzle) -> PathBuf {
+        self.year_dir(puzzle.year)
+            .join(format!("day{}.txt", puzzle.day))
+    }
+
+    /// Gets the path of @example's input file
+    pub fn example_path(&self, puzzle: &Puzzle, example: &Example) -> PathBuf {
+        self.year_dir(puzzle.year).join(example.file)
+    }
+
+    /// Reads the input for @puzzle
+    pub fn read(&self, puzzle: &Puzzle) -> io::Result<String> {
+        read_file(&self.path(puzzle))
+    }
+}
+
+/// Reads @path, adding the path to the error message if it can't be read
+pub fn read_file(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+}
+
+/// Writes @contents to @path by writing a temporary file next to it and renaming
+/// that over @path, so that @path is never left partially written
+pub fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    let name = path
+        .file_name()
+        .ok_or_else(|| io::Error::other(format!("{} isn't a file", path.display())))?;
+    let tmp = path.with_file_name(format!(".{}.tmp", name.to_string_lossy()));
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let result = fs::write(&tmp, contents).and_then(|_| fs::rename(&tmp, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+
+    result
+}
+
+/// Where to read a Puzzle's input from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// The Puzzle's file in the input directory
+    Default,
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Parses an --input argument, where "-" means stdin
+    pub fn from_arg(arg: Option<&Path>) -> Self {
+        match arg {
+            Some(path) if path == Path::new("-") => Source::Stdin,
+            Some(path) => Source::File(path.to_owned()),
+            None => Source::Default,
+        }
+    }
+
+    /// Reads the input for @puzzle from this Source. Stdin can only be read once,
+    /// so it should only be used for a single Puzzle
+    pub fn read(&self, inputs: &Inputs, puzzle: &Puzzle) -> io::Result<String> {
+        match self {
+            Source::Default => inputs.read(puzzle),
+            Source::File(path) => read_file(path),
+            Source::Stdin => {
+                let mut buf = String::new();
+                io::stdin().read_to_string(&mut buf)?;
+                Ok(buf)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::AocError;
+    use crate::solution::{Answer, Solution};
+
+    struct Day7;
+    impl Solution for Day7 {
+        const YEAR: u32 = 2024;
+        const DAY: usize = 7;
+        const EXAMPLES: &'static [Example] = &[Example {
+            file: "day7_example.txt",
+            part1: None,
+            part2: None,
+        }];
+        type Input<'a> = &'a str;
+        fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+            Ok(input)
+        }
+        fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+            Ok(input.len().into())
+        }
+    }
+
+    #[test]
+    fn paths() {
+        let inputs = Inputs::new(Path::new("/data/aoc"));
+        let puzzle = Puzzle::new::<Day7>();
+
+        assert_eq!(inputs.path(&puzzle), Path::new("/data/aoc/2024/day7.txt"));
+        assert_eq!(
+            inputs.example_path(&puzzle, &puzzle.examples[0]),
+            Path::new("/data/aoc/2024/day7_example.txt")
+        );
+    }
+
+    #[test]
+    fn sources() {
+        assert_eq!(Source::from_arg(None), Source::Default);
+        assert_eq!(Source::from_arg(Some(Path::new("-"))), Source::Stdin);
+        assert_eq!(
+            Source::from_arg(Some(Path::new("in.txt"))),
+            Source::File(PathBuf::from("in.txt"))
+        );
+
+        let err = read_file(Path::new("/does/not/exist.txt")).unwrap_err();
+        assert!(err.to_string().contains("/does/not/exist.txt"));
+    }
+
+    #[test]
+    fn atomic_writes() {
+        let dir = env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+        let path = dir.join("2024").join("day1.txt");
+
+        write_atomic(&path, "first").unwrap();
+        write_atomic(&path, "second").unwrap();
+
+        let files = fs::read_dir(path.parent().unwrap()).unwrap().count();
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(contents, "second");
+        assert_eq!(files, 1);
+    }
+}
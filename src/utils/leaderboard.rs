@@ -0,0 +1,221 @@
+//! Private leaderboards, as served by <year>/leaderboard/private/view/<id>.json
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use serde::Deserialize;
+
+use crate::client::AocClient;
+use crate::input;
+
+/// Directory fetched leaderboards are cached in
+pub const CACHE_DIR: &str = "cache";
+/// How long a cached leaderboard is used before fetching it again. The site asks
+/// that leaderboards aren't requested more often than this
+pub const CACHE_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// When a star was earned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct Star {
+    pub get_star_ts: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// None for anonymous users
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    pub last_star_ts: u64,
+    /// The stars earned on each day, by part
+    pub completion_day_level: BTreeMap<usize, BTreeMap<usize, Star>>,
+}
+
+impl Member {
+    /// Gets the member's name, or how the site shows anonymous users
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// Gets the number of stars earned on @day
+    pub fn stars_on(&self, day: usize) -> usize {
+        self.completion_day_level.get(&day).map_or(0, |d| d.len())
+    }
+
+    /// Gets when @part of @day was completed, as a unix timestamp
+    pub fn completed(&self, day: usize, part: usize) -> Option<u64> {
+        self.completion_day_level
+            .get(&day)?
+            .get(&part)
+            .map(|s| s.get_star_ts)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub owner_id: u64,
+    pub members: BTreeMap<String, Member>,
+}
+
+impl Leaderboard {
+    /// Parses the JSON of a private leaderboard
+    pub fn parse(json: &str) -> io::Result<Self> {
+        serde_json::from_str(json).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Could not parse leaderboard: {}", e),
+            )
+        })
+    }
+
+    /// Gets the members ordered by local score, then stars, with ties going to
+    /// whoever got their last star first
+    pub fn standings(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+
+        members.sort_by_key(|m| {
+            (
+                Reverse(m.local_score),
+                Reverse(m.stars),
+                m.last_star_ts,
+                m.id,
+            )
+        });
+        members
+    }
+}
+
+/// Gets the unix timestamp of when @day of @year unlocks, which is midnight EST
+pub fn unlock_time(year: u32, day: usize) -> u64 {
+    // days since 1970-01-01 of December @day, from Howard Hinnant's days_from_civil
+    let y = year as i64;
+    let era = y / 400;
+    let yoe = y - era * 400;
+    let doy = (153 * 9 + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+
+    (days * 86400 + 5 * 3600) as u64
+}
+
+/// Gets the path @id's leaderboard for @year is cached at
+pub fn cache_path(dir: &Path, year: u32, id: u64) -> PathBuf {
+    dir.join(format!("leaderboard_{}_{}.json", year, id))
+}
+
+/// Reads the leaderboard cached at @path, along with its age, if it's younger than @ttl
+fn cached(path: &Path, ttl: Duration) -> Option<(String, Duration)> {
+    let modified = fs::metadata(path).ok()?.modified().ok()?;
+    let age = SystemTime::now()
+        .duration_since(modified)
+        .unwrap_or_default();
+
+    if age < ttl {
+        Some((fs::read_to_string(path).ok()?, age))
+    } else {
+        None
+    }
+}
+
+/// Gets private leaderboard @id for @year, using the copy cached in @dir if it was
+/// fetched in the last 15 minutes. Returns the leaderboard and how old it is
+pub async fn load(
+    client: &AocClient,
+    dir: &Path,
+    year: u32,
+    id: u64,
+) -> io::Result<(Leaderboard, Duration)> {
+    let path = cache_path(dir, year, id);
+
+    if let Some((json, age)) = cached(&path, CACHE_TTL) {
+        return Ok((Leaderboard::parse(&json)?, age));
+    }
+
+    let json = client.leaderboard(year, id).await?;
+    // the site redirects to an HTML page for leaderboards the user can't see
+    let leaderboard = Leaderboard::parse(&json).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!(
+                "{}. Is {} the id of a leaderboard you're a member of?",
+                e, id
+            ),
+        )
+    })?;
+
+    input::write_atomic(&path, &json)?;
+    Ok((leaderboard, Duration::ZERO))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::mock;
+
+    const FIXTURE: &str = include_str!("fixtures/leaderboard.json");
+
+    #[test]
+    fn parse() {
+        let board = Leaderboard::parse(FIXTURE).unwrap();
+        let ada = &board.members["1234"];
+
+        assert_eq!(board.event, "2024");
+        assert_eq!(board.members.len(), 4);
+        assert_eq!(ada.stars, 5);
+        assert_eq!(ada.stars_on(2), 2);
+        assert_eq!(ada.stars_on(3), 1);
+        assert_eq!(ada.completed(1, 2), Some(1733030102));
+        assert_eq!(ada.completed(3, 2), None);
+        assert_eq!(
+            board.members["9012"].display_name(),
+            "(anonymous user #9012)"
+        );
+
+        assert!(Leaderboard::parse("<html></html>").is_err());
+    }
+
+    #[test]
+    fn standings() {
+        let board = Leaderboard::parse(FIXTURE).unwrap();
+        let names: Vec<String> = board.standings().iter().map(|m| m.display_name()).collect();
+
+        assert_eq!(
+            names,
+            ["grace", "Ada Lovelace", "(anonymous user #9012)", "Linus"]
+        );
+    }
+
+    #[test]
+    fn unlock_times() {
+        assert_eq!(unlock_time(2024, 1), 1733029200);
+        assert_eq!(unlock_time(2015, 25), 1451019600);
+    }
+
+    #[tokio::test]
+    async fn caching() {
+        let dir = std::env::temp_dir().join(format!("aoc-leaderboard-{}", std::process::id()));
+        let (url, requests) = mock::serve(vec![(200, FIXTURE.to_owned())]);
+        let client = AocClient::with_base_url(&url, "abc123").with_throttle(Duration::ZERO);
+
+        let (first, age) = load(&client, &dir, 2024, 1234).await.unwrap();
+        assert_eq!(age, Duration::ZERO);
+
+        // the mock server only answers once, so this has to come from the cache
+        let (second, _) = load(&client, &dir, 2024, 1234).await.unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        let requests: Vec<String> = requests.try_iter().collect();
+
+        assert_eq!(first, second);
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2024/leaderboard/private/view/1234.json "));
+    }
+}
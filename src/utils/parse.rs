@@ -0,0 +1,121 @@
+//! Helpers for parsing puzzle inputs that report where the input went wrong
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::error::AocError;
+
+/// Parses every line of @input as a T
+pub fn lines<T>(input: &str) -> Result<Vec<T>, AocError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            line.parse()
+                .map_err(|e| AocError::parse(i + 1, 1, format!("\"{}\": {}", line, e)))
+        })
+        .collect()
+}
+
+/// Parses the numbers on each line of @input. Numbers can be separated by
+/// whitespace or commas
+pub fn numbers<T>(input: &str) -> Result<Vec<Vec<T>>, AocError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            tokens(line)
+                .map(|(column, token)| {
+                    token.parse().map_err(|e| {
+                        AocError::parse(i + 1, column, format!("\"{}\": {}", token, e))
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Splits @line on whitespace and commas, along with the column each token starts at
+fn tokens(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|t| !t.is_empty())
+        .map(move |t| (t.as_ptr() as usize - line.as_ptr() as usize + 1, t))
+}
+
+/// Parses @input as a grid of characters, indexed by [line][column]. Every line
+/// has to be the same length
+pub fn char_grid(input: &str) -> Result<Vec<Vec<char>>, AocError> {
+    let grid: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
+
+    if let Some(first) = grid.first() {
+        for (i, row) in grid.iter().enumerate() {
+            if row.len() != first.len() {
+                return Err(AocError::parse(
+                    i + 1,
+                    row.len().min(first.len()) + 1,
+                    format!("expected {} columns, found {}", first.len(), row.len()),
+                ));
+            }
+        }
+    }
+
+    Ok(grid)
+}
+
+/// Parses @input as a grid of single digits, indexed by [line][column]
+pub fn digit_grid(input: &str) -> Result<Vec<Vec<u8>>, AocError> {
+    let grid = char_grid(input)?;
+
+    grid.iter()
+        .enumerate()
+        .map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(j, c)| {
+                    c.to_digit(10).map(|d| d as u8).ok_or_else(|| {
+                        AocError::parse(i + 1, j + 1, format!("'{}' isn't a digit", c))
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(e: AocError) -> (usize, usize) {
+        match e {
+            AocError::Parse { line, column, .. } => (line, column),
+            e => panic!("expected a parse error, got {}", e),
+        }
+    }
+
+    #[test]
+    fn numbers_and_lines() {
+        assert_eq!(
+            numbers::<i32>("3   4\n-1,2, 5\n").unwrap(),
+            [vec![3, 4], vec![-1, 2, 5]]
+        );
+        assert_eq!(position(numbers::<u32>("1 2\n3 x4").unwrap_err()), (2, 3));
+
+        assert_eq!(lines::<u64>("10\n20").unwrap(), [10, 20]);
+        assert_eq!(position(lines::<u64>("10\n\n20").unwrap_err()), (2, 1));
+    }
+
+    #[test]
+    fn grids() {
+        assert_eq!(digit_grid("01\n23").unwrap(), [vec![0, 1], vec![2, 3]]);
+        assert_eq!(position(digit_grid("01\n2.").unwrap_err()), (2, 2));
+        assert_eq!(position(char_grid("..#\n.#\n").unwrap_err()), (2, 3));
+    }
+}
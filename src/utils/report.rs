@@ -0,0 +1,376 @@
+//! Reports of a year's results, as Markdown or a standalone HTML page
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::solution::{PartResult, RunResult};
+
+/// Results of one day, and the aocutils modules its solution uses
+#[derive(Debug)]
+pub struct DayReport {
+    pub day: usize,
+    /// The day's results, or why there aren't any
+    pub result: Result<RunResult, String>,
+    pub modules: Vec<String>,
+}
+
+impl DayReport {
+    fn total(&self) -> Option<Duration> {
+        self.result
+            .as_ref()
+            .ok()
+            .map(|r| r.parse + r.part1.time + r.part2.time)
+    }
+}
+
+/// Report of every day of a year
+#[derive(Debug)]
+pub struct Report {
+    pub year: u32,
+    pub days: Vec<DayReport>,
+    /// Whether answers are hidden, so that the report can be shared
+    pub redact: bool,
+}
+
+/// Width of the longest bar in Markdown bar charts, in characters
+const BAR_WIDTH: usize = 40;
+
+impl Report {
+    fn answer(&self, part: &PartResult) -> String {
+        match &part.error {
+            Some(e) => format!("Error! ({})", e),
+            None if self.redact && part.answer.is_solved() => String::from("[redacted]"),
+            None => part.answer.to_string(),
+        }
+    }
+
+    fn longest(&self) -> Duration {
+        self.days
+            .iter()
+            .filter_map(|d| d.total())
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn total(&self) -> Duration {
+        self.days.iter().filter_map(|d| d.total()).sum()
+    }
+
+    /// Renders this Report as Markdown
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("# Advent of Code {}\n\n", self.year);
+
+        out.push_str("| day | part 1 | part 2 | parse | part 1 time | part 2 time | modules |\n");
+        out.push_str("| --: | --- | --- | --: | --: | --: | --- |\n");
+
+        for d in &self.days {
+            let modules = d.modules.join(", ");
+
+            match &d.result {
+                Ok(r) => writeln!(
+                    out,
+                    "| {} | {} | {} | {:.2?} | {:.2?} | {:.2?} | {} |",
+                    d.day,
+                    markdown_cell(&self.answer(&r.part1)),
+                    markdown_cell(&self.answer(&r.part2)),
+                    r.parse,
+                    r.part1.time,
+                    r.part2.time,
+                    modules
+                ),
+                Err(e) => writeln!(
+                    out,
+                    "| {} | {} | | | | | {} |",
+                    d.day,
+                    markdown_cell(e),
+                    modules
+                ),
+            }
+            .unwrap();
+        }
+
+        writeln!(out, "\nTotal runtime: {:.2?}\n", self.total()).unwrap();
+        out.push_str("## Runtime\n\n```\n");
+
+        let longest = self.longest();
+        for d in &self.days {
+            if let Some(total) = d.total() {
+                let bar = "#".repeat(scale(total, longest, BAR_WIDTH));
+
+                writeln!(out, "day {:>2}  {:<BAR_WIDTH$}  {:.2?}", d.day, bar, total).unwrap();
+            }
+        }
+
+        out.push_str("```\n");
+        out
+    }
+
+    /// Renders this Report as a standalone HTML page
+    pub fn to_html(&self) -> String {
+        let title = format!("Advent of Code {}", self.year);
+        let mut out = String::new();
+
+        writeln!(
+            out,
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>",
+            title
+        )
+        .unwrap();
+        out.push_str(STYLE);
+        writeln!(out, "</head>\n<body>\n<h1>{}</h1>", title).unwrap();
+
+        out.push_str("<table>\n<tr><th>day</th><th>part 1</th><th>part 2</th><th>parse</th>");
+        out.push_str("<th>part 1 time</th><th>part 2 time</th><th>modules</th></tr>\n");
+
+        for d in &self.days {
+            let modules = escape(&d.modules.join(", "));
+
+            match &d.result {
+                Ok(r) => writeln!(
+                    out,
+                    "<tr><td>{}</td><td><pre>{}</pre></td><td><pre>{}</pre></td>\
+                     <td class=\"time\">{:.2?}</td><td class=\"time\">{:.2?}</td>\
+                     <td class=\"time\">{:.2?}</td><td>{}</td></tr>",
+                    d.day,
+                    escape(&self.answer(&r.part1)),
+                    escape(&self.answer(&r.part2)),
+                    r.parse,
+                    r.part1.time,
+                    r.part2.time,
+                    modules
+                ),
+                Err(e) => writeln!(
+                    out,
+                    "<tr><td>{}</td><td colspan=\"5\" class=\"error\">{}</td><td>{}</td></tr>",
+                    d.day,
+                    escape(e),
+                    modules
+                ),
+            }
+            .unwrap();
+        }
+
+        out.push_str("</table>\n");
+        writeln!(out, "<p>Total runtime: {:.2?}</p>", self.total()).unwrap();
+        out.push_str("<h2>Runtime</h2>\n<div class=\"chart\">\n");
+
+        // each bar is split into the time spent parsing and on each part
+        let longest = self.longest().as_secs_f64();
+        for d in &self.days {
+            let (Ok(r), Some(total)) = (&d.result, d.total()) else {
+                continue;
+            };
+
+            writeln!(
+                out,
+                "<div class=\"row\"><span class=\"label\">day {}</span><span class=\"bars\">",
+                d.day
+            )
+            .unwrap();
+            for (class, time) in [
+                ("parse", r.parse),
+                ("part1", r.part1.time),
+                ("part2", r.part2.time),
+            ] {
+                let width = if longest > 0.0 {
+                    time.as_secs_f64() / longest * 100.0
+                } else {
+                    0.0
+                };
+
+                writeln!(
+                    out,
+                    "<span class=\"bar {}\" style=\"width: {:.3}%\" title=\"{} {:.2?}\"></span>",
+                    class, width, class, time
+                )
+                .unwrap();
+            }
+            writeln!(
+                out,
+                "</span><span class=\"total\">{:.2?}</span></div>",
+                total
+            )
+            .unwrap();
+        }
+
+        out.push_str("</div>\n<p class=\"legend\"><span class=\"bar parse\"></span> parse ");
+        out.push_str("<span class=\"bar part1\"></span> part 1 ");
+        out.push_str("<span class=\"bar part2\"></span> part 2</p>\n</body>\n</html>\n");
+        out
+    }
+}
+
+const STYLE: &str = "<style>
+body { font-family: sans-serif; margin: 2em; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #ccc; padding: 0.3em 0.6em; vertical-align: top; }
+pre { margin: 0; }
+.time { text-align: right; font-family: monospace; }
+.error { color: #b00; }
+.chart { width: 50em; }
+.row { display: flex; align-items: center; margin: 2px 0; }
+.label { width: 4em; }
+.bars { flex: 1; display: flex; }
+.total { width: 7em; text-align: right; font-family: monospace; }
+.bar { display: inline-block; height: 1em; min-width: 1px; }
+.legend .bar { width: 1em; }
+.parse { background: #999; }
+.part1 { background: #9999cc; }
+.part2 { background: #ffff66; }
+</style>
+";
+
+/// Gets how many of @width characters a bar for @time takes up, when the bar for
+/// @longest takes all of them. Days that took any time get at least one
+fn scale(time: Duration, longest: Duration, width: usize) -> usize {
+    if longest.is_zero() {
+        return 0;
+    }
+
+    let n = (time.as_secs_f64() / longest.as_secs_f64() * width as f64).round() as usize;
+    n.max(usize::from(!time.is_zero()))
+}
+
+/// Makes @text safe to put in a Markdown table cell. Multi-line answers are kept
+/// on separate lines with <br>
+fn markdown_cell(text: &str) -> String {
+    let lines: Vec<String> = text
+        .trim_end()
+        .lines()
+        .map(|l| format!("`{}`", l.replace('|', "\\|")))
+        .collect();
+
+    lines.join("<br>")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn ident(text: &str) -> String {
+    text.trim_start()
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect()
+}
+
+/// Finds the aocutils modules imported by the solution in @source, sorted. The
+/// solution and error modules are left out, since every day uses them
+pub fn modules_used(source: &str) -> Vec<String> {
+    let mut modules = Vec::new();
+
+    for (i, _) in source.match_indices("aocutils::") {
+        let rest = &source[i + "aocutils::".len()..];
+
+        let Some(group) = rest.strip_prefix('{') else {
+            modules.push(ident(rest));
+            continue;
+        };
+
+        // only the first segment of each item at the top level of the braces matters
+        let mut depth = 0;
+        let mut item = 0;
+
+        for (j, c) in group.char_indices() {
+            match c {
+                '{' => depth += 1,
+                '}' if depth > 0 => depth -= 1,
+                '}' | ',' if depth == 0 => {
+                    modules.push(ident(&group[item..j]));
+                    item = j + 1;
+
+                    if c == '}' {
+                        break;
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+
+    modules.retain(|m| !m.is_empty() && !["error", "self", "solution"].contains(&m.as_str()));
+    modules.sort();
+    modules.dedup();
+    modules
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Answer;
+
+    fn report(redact: bool) -> Report {
+        let part = |answer: Answer, micros| PartResult {
+            answer,
+            time: Duration::from_micros(micros),
+            memory: None,
+            error: None,
+        };
+        let result = RunResult {
+            year: 2024,
+            day: 1,
+            parse: Duration::from_micros(10),
+            parse_memory: None,
+            part1: part(Answer::from(11), 40),
+            part2: part(Answer::from("#..#\n####"), 50),
+        };
+
+        Report {
+            year: 2024,
+            days: vec![
+                DayReport {
+                    day: 1,
+                    result: Ok(result),
+                    modules: vec![String::from("coord"), String::from("grid")],
+                },
+                DayReport {
+                    day: 2,
+                    result: Err(String::from("no <input>")),
+                    modules: Vec::new(),
+                },
+            ],
+            redact,
+        }
+    }
+
+    #[test]
+    fn modules() {
+        let source = "use aocutils::coord::Coord;\n\
+                      use aocutils::error::AocError;\n\
+                      use aocutils::grid::{direction::*, in_bounds};\n\
+                      use aocutils::{graph::{Graph, Node}, direction};\n\
+                      use aocutils::solution::{Answer, Solution};\n";
+
+        assert_eq!(
+            modules_used(source),
+            ["coord", "direction", "graph", "grid"]
+        );
+    }
+
+    #[test]
+    fn markdown() {
+        let md = report(false).to_markdown();
+
+        assert!(md.contains(
+            "| 1 | `11` | `#..#`<br>`####` | 10.00µs | 40.00µs | 50.00µs | coord, grid |"
+        ));
+        assert!(md.contains("| 2 | `no <input>` |"));
+        assert!(md.contains(&format!("day  1  {}  100.00µs", "#".repeat(BAR_WIDTH))));
+
+        let redacted = report(true).to_markdown();
+        assert!(!redacted.contains("`11`"));
+        assert!(redacted.contains("`[redacted]`"));
+    }
+
+    #[test]
+    fn html() {
+        let html = report(false).to_html();
+
+        assert!(html.contains("<pre>#..#\n####</pre>"));
+        assert!(html.contains("no &lt;input&gt;"));
+        assert!(html.contains("class=\"bar part2\" style=\"width: 50.000%\""));
+    }
+}
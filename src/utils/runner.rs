@@ -0,0 +1,186 @@
+use std::io;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, mpsc};
+use std::thread;
+use std::time::Duration;
+
+use crate::error::AocError;
+use crate::solution::{Puzzle, RunResult};
+
+/// Stack size of the threads solutions run on. Some solutions recurse deeply, so
+/// this is bigger than the default for spawned threads
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// Runs @puzzle on @input on its own thread. Panics are caught for each part, so
+/// that one part panicking doesn't lose the other's answer. If it takes longer than
+/// @timeout, the thread is abandoned: it can't be stopped, but nothing waits for it
+/// anymore and it dies with the process
+pub fn run_isolated(
+    puzzle: Puzzle,
+    input: String,
+    timeout: Option<Duration>,
+) -> Result<RunResult, AocError> {
+    let (tx, rx) = mpsc::channel();
+
+    thread::Builder::new()
+        .name(format!("{}-day{}", puzzle.year, puzzle.day))
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let _ = tx.send(puzzle.run(&input));
+        })?;
+
+    match timeout {
+        Some(t) => rx.recv_timeout(t).map_err(|_| AocError::Timeout(t))?,
+        None => rx
+            .recv()
+            .map_err(|_| AocError::Panic(String::from("thread exited without a result")))?,
+    }
+}
+
+/// How to run a set of Puzzles
+#[derive(Debug, Clone, Copy)]
+pub struct RunOptions {
+    /// Number of Puzzles run at the same time
+    pub jobs: usize,
+    /// Wall-clock time limit for each Puzzle
+    pub timeout: Option<Duration>,
+}
+
+/// Runs every Puzzle in @puzzles, @opts.jobs at a time, using @load to get each
+/// Puzzle's input. Results are sent as soon as they're done, along with the index
+/// of their Puzzle in @puzzles, so they may arrive out of order
+pub fn run_all<L>(
+    puzzles: Vec<Puzzle>,
+    opts: RunOptions,
+    load: L,
+) -> mpsc::Receiver<(usize, Result<RunResult, AocError>)>
+where
+    L: Fn(&Puzzle) -> io::Result<String> + Send + Sync + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+    let puzzles = Arc::new(puzzles);
+    let load = Arc::new(load);
+    let next = Arc::new(AtomicUsize::new(0));
+
+    for _ in 0..opts.jobs.clamp(1, puzzles.len().max(1)) {
+        let (tx, puzzles, load, next) = (tx.clone(), puzzles.clone(), load.clone(), next.clone());
+
+        thread::spawn(move || {
+            loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(puzzle) = puzzles.get(i) else {
+                    break;
+                };
+
+                let result = load(puzzle)
+                    .map_err(AocError::from)
+                    .and_then(|input| run_isolated(*puzzle, input, opts.timeout));
+
+                if tx.send((i, result)).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{Answer, Solution};
+
+    struct Slow;
+    impl Solution for Slow {
+        const YEAR: u32 = 2000;
+        const DAY: usize = 1;
+        type Input<'a> = &'a str;
+        fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+            Ok(input)
+        }
+        fn part1(_input: &Self::Input<'_>) -> Result<Answer, AocError> {
+            thread::sleep(Duration::from_secs(5));
+            Ok(Answer::from(1))
+        }
+    }
+
+    struct Panics;
+    impl Solution for Panics {
+        const YEAR: u32 = 2000;
+        const DAY: usize = 2;
+        type Input<'a> = &'a str;
+        fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+            Ok(input)
+        }
+        fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+            Ok(input.parse::<u32>().unwrap().into())
+        }
+        fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+            Ok(input.len().into())
+        }
+    }
+
+    struct Works;
+    impl Solution for Works {
+        const YEAR: u32 = 2000;
+        const DAY: usize = 3;
+        type Input<'a> = &'a str;
+        fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+            Ok(input)
+        }
+        fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+            Ok(input.len().into())
+        }
+        fn part2(_input: &Self::Input<'_>) -> Result<Answer, AocError> {
+            Err(AocError::unsolvable("no path"))
+        }
+    }
+
+    struct BadInput;
+    impl Solution for BadInput {
+        const YEAR: u32 = 2000;
+        const DAY: usize = 4;
+        type Input<'a> = u32;
+        fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+            input.parse().map_err(|e| AocError::parse(1, 1, e))
+        }
+    }
+
+    #[test]
+    fn failures_are_isolated() {
+        let puzzles = vec![
+            Puzzle::new::<Panics>(),
+            Puzzle::new::<Works>(),
+            Puzzle::new::<BadInput>(),
+        ];
+        let opts = RunOptions {
+            jobs: 3,
+            timeout: Some(Duration::from_secs(10)),
+        };
+
+        let mut results: Vec<_> = run_all(puzzles, opts, |_| Ok(String::from("abc")))
+            .iter()
+            .collect();
+        results.sort_by_key(|(i, _)| *i);
+
+        // only Slow gets a short deadline, so that nothing else can run into it
+        let slow = run_isolated(
+            Puzzle::new::<Slow>(),
+            String::from("abc"),
+            Some(Duration::from_millis(100)),
+        );
+        assert!(matches!(slow, Err(AocError::Timeout(_))));
+        assert!(matches!(results[2].1, Err(AocError::Parse { line: 1, .. })));
+
+        // a part panicking or failing doesn't affect the other part
+        let panics = results[0].1.as_ref().unwrap();
+        assert!(matches!(panics.part1.error, Some(AocError::Panic(_))));
+        assert_eq!(panics.part1.answer, Answer::Unsolved);
+        assert_eq!(panics.part2.answer, Answer::from(3));
+
+        let works = results[1].1.as_ref().unwrap();
+        assert_eq!(works.part1.answer, Answer::from(3));
+        assert!(matches!(works.part2.error, Some(AocError::Unsolvable(_))));
+    }
+}
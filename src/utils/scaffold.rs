@@ -0,0 +1,22 @@
+//! Generates the files for a new day
+
+/// Fills in the placeholders of the day template: 20XX is replaced with @year
+/// and YY with @day
+pub fn render_template(template: &str, year: u32, day: usize) -> String {
+    template
+        .replace("20XX", &year.to_string())
+        .replace("YY", &day.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn template() {
+        assert_eq!(
+            render_template("struct DayYY; // inputs/20XX/dayYY_example.txt", 2024, 7),
+            "struct Day7; // inputs/2024/day7_example.txt"
+        );
+    }
+}
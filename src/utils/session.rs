@@ -0,0 +1,87 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Environment variable holding the session cookie
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Gets the path of the session file in the user's config directory,
+/// i.e. ~/.config/aoc/session
+pub fn config_path() -> Option<PathBuf> {
+    let config = match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(env::var_os("HOME")?).join(".config"),
+    };
+
+    Some(config.join("aoc").join("session"))
+}
+
+fn read(path: &Path) -> io::Result<String> {
+    let key = fs::read_to_string(path).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!("Could not read session file {}: {}", path.display(), e),
+        )
+    })?;
+
+    check(key, &path.display().to_string())
+}
+
+fn check(key: String, source: &str) -> io::Result<String> {
+    let key = key.trim();
+
+    if key.is_empty() {
+        Err(io::Error::other(format!(
+            "Session from {} is empty",
+            source
+        )))
+    } else {
+        Ok(key.to_owned())
+    }
+}
+
+/// Finds the session cookie. In order, it's read from the file at @file,
+/// $AOC_SESSION, or the session file in the config directory
+pub fn resolve(file: Option<&Path>) -> io::Result<String> {
+    if let Some(path) = file {
+        return read(path);
+    }
+
+    if let Some(key) = env::var_os(SESSION_VAR) {
+        return check(key.to_string_lossy().into_owned(), SESSION_VAR);
+    }
+
+    match config_path() {
+        Some(path) if path.exists() => read(&path),
+        path => Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "No session cookie found. Set {}, put it in {}, or pass --session <file>",
+                SESSION_VAR,
+                path.map_or(String::from("~/.config/aoc/session"), |p| {
+                    p.display().to_string()
+                })
+            ),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn session_file() {
+        let path = env::temp_dir().join(format!("aoc-session-{}", std::process::id()));
+
+        fs::write(&path, "  abc123\n").unwrap();
+        assert_eq!(resolve(Some(&path)).unwrap(), "abc123");
+
+        fs::write(&path, "\n").unwrap();
+        assert!(resolve(Some(&path)).is_err());
+
+        fs::remove_file(&path).unwrap();
+        assert!(resolve(Some(&path)).is_err());
+    }
+}
@@ -0,0 +1,427 @@
+use std::cell::{Cell, RefCell};
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::alloc::{self, MemStats};
+use crate::error::{self, AocError};
+
+/// Answer to one part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    #[default]
+    Unsolved,
+}
+
+impl Answer {
+    pub fn is_solved(&self) -> bool {
+        !matches!(self, Answer::Unsolved)
+    }
+
+    /// Reads @s as an Integer if that's how the integer would be written, and as
+    /// Text otherwise
+    fn parse_text(s: String) -> Self {
+        match s.parse::<i128>() {
+            Ok(n) if n.to_string() == s => Answer::Integer(n),
+            _ => Answer::Text(s),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+/// Integers are serialized as numbers, text as strings and unsolved
+/// answers as null. Integers too big for an i64 are serialized as strings,
+/// since not every format supports 128-bit integers, and are read back as
+/// integers
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Integer(n) => match i64::try_from(*n) {
+                Ok(n) => serializer.serialize_i64(n),
+                Err(_) => serializer.serialize_str(&n.to_string()),
+            },
+            Answer::Text(s) => serializer.serialize_str(s),
+            Answer::Unsolved => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Integer(i64),
+            Text(String),
+        }
+
+        Ok(match Option::<Raw>::deserialize(deserializer)? {
+            Some(Raw::Integer(n)) => Answer::Integer(n.into()),
+            Some(Raw::Text(s)) => Answer::parse_text(s),
+            None => Answer::Unsolved,
+        })
+    }
+}
+
+macro_rules! answer_from_int {
+    ( $($t:ty),+ ) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Integer(value as i128)
+                }
+            }
+        )+
+    };
+}
+
+answer_from_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+/// Parses integers as Answer::Integer and anything else as text. Only strings
+/// that an integer is written as exactly count as integers, so that "007" or
+/// "+5" stay the same text
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Answer::parse_text(s.trim().to_owned()))
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        value.map(|v| v.into()).unwrap_or_default()
+    }
+}
+
+/// Example input for a day and the answers expected for it. Example files
+/// are stored next to the real inputs, in inputs/<year>/<file>
+///
+/// Parts without an expected answer aren't checked, since not every example
+/// covers both parts.
+#[derive(Debug, Clone, Copy)]
+pub struct Example {
+    pub file: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+impl Example {
+    /// Gets the expected answer for @part (1 or 2), if there is one
+    pub fn part(&self, part: usize) -> Option<Answer> {
+        match part {
+            1 => self.part1.map(Answer::from),
+            2 => self.part2.map(Answer::from),
+            _ => None,
+        }
+    }
+}
+
+/// A single day's puzzle solution
+///
+/// The raw puzzle input is handed to @parse once, and the result is shared
+/// between @part1 and @part2. Days that don't need a separate parsing step
+/// can just use the input as is (type Input<'a> = &'a str).
+///
+/// Input that can't be parsed should be reported with AocError::Parse, and
+/// input without an answer with AocError::Unsolvable. Panics are caught, but
+/// only say what went wrong, not where in the input.
+pub trait Solution {
+    const YEAR: u32;
+    const DAY: usize;
+
+    /// Example inputs checked by "cargo test"
+    const EXAMPLES: &'static [Example] = &[];
+
+    /// Parsed form of the puzzle input
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError>;
+
+    fn part1(_input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+pub(crate) fn as_nanos<S: Serializer>(time: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(time.as_nanos())
+}
+
+fn as_message<S: Serializer>(error: &Option<AocError>, serializer: S) -> Result<S::Ok, S::Error> {
+    match error {
+        Some(e) => serializer.collect_str(e),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Answer to one part of a Puzzle and how long it took to compute
+#[derive(Debug, Serialize)]
+pub struct PartResult {
+    /// Unsolved if the part failed
+    pub answer: Answer,
+    #[serde(rename = "time_ns", serialize_with = "as_nanos")]
+    pub time: Duration,
+    /// Only measured when memory profiling is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<MemStats>,
+    /// Why the part failed, if it returned an error or panicked
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "as_message")]
+    pub error: Option<AocError>,
+}
+
+/// Results of running a Puzzle on an input
+#[derive(Debug, Serialize)]
+pub struct RunResult {
+    pub year: u32,
+    pub day: usize,
+    #[serde(rename = "parse_ns", serialize_with = "as_nanos")]
+    pub parse: Duration,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_memory: Option<MemStats>,
+    pub part1: PartResult,
+    pub part2: PartResult,
+}
+
+impl RunResult {
+    /// Gets the PartResult for @part (1 or 2)
+    pub fn part(&self, part: usize) -> Option<&PartResult> {
+        match part {
+            1 => Some(&self.part1),
+            2 => Some(&self.part2),
+            _ => None,
+        }
+    }
+}
+
+/// Type erased entry for a Solution, so that every day of every
+/// year can be stored in the same registry
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub year: u32,
+    pub day: usize,
+    pub examples: &'static [Example],
+    run: fn(&str) -> Result<RunResult, AocError>,
+}
+
+impl Puzzle {
+    pub const fn new<S: Solution>() -> Self {
+        Puzzle {
+            year: S::YEAR,
+            day: S::DAY,
+            examples: S::EXAMPLES,
+            run: run_solution::<S>,
+        }
+    }
+
+    /// Parses @input and runs both parts of this Puzzle on it. Fails if @input
+    /// can't be parsed, while parts that fail only have their error recorded
+    pub fn run(&self, input: &str) -> Result<RunResult, AocError> {
+        (self.run)(input)
+    }
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let ret = f();
+
+    (ret, start.elapsed())
+}
+
+static QUIET_HOOK: Once = Once::new();
+
+thread_local! {
+    /// Whether panics on this thread are caught by catch, and shouldn't be printed
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+    /// Where the last panic caught by catch on this thread happened
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs @f, turning a panic into an error with the panic's message and location.
+/// The default panic hook would print the panic and its backtrace in the middle
+/// of the results, so panics inside @f aren't printed. Panics anywhere else are
+/// left to the hook that was there before
+fn catch<T>(f: impl FnOnce() -> Result<T, AocError>) -> Result<T, AocError> {
+    QUIET_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if QUIET.get() {
+                PANIC_LOCATION.set(info.location().map(|l| l.to_string()));
+            } else {
+                previous(info);
+            }
+        }));
+    });
+
+    let quiet = QUIET.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    QUIET.set(quiet);
+
+    result.unwrap_or_else(|p| Err(error::from_panic(&*p, PANIC_LOCATION.take())))
+}
+
+fn run_part(f: impl FnOnce() -> Result<Answer, AocError>) -> PartResult {
+    let ((result, time), memory) = alloc::measure(|| time(|| catch(f)));
+    let (answer, error) = match result {
+        Ok(answer) => (answer, None),
+        Err(e) => (Answer::Unsolved, Some(e)),
+    };
+
+    PartResult {
+        answer,
+        time,
+        memory,
+        error,
+    }
+}
+
+fn run_solution<S: Solution>(input: &str) -> Result<RunResult, AocError> {
+    let ((input, parse), parse_memory) = alloc::measure(|| time(|| catch(|| S::parse(input))));
+    let input = input?;
+
+    Ok(RunResult {
+        year: S::YEAR,
+        day: S::DAY,
+        parse,
+        parse_memory,
+        part1: run_part(|| S::part1(&input)),
+        part2: run_part(|| S::part2(&input)),
+    })
+}
+
+/// Collection of every Puzzle, grouped by year
+pub struct Registry {
+    years: Vec<&'static [Puzzle]>,
+}
+
+impl Registry {
+    /// Creates a Registry from a list of years, where each year is a
+    /// list of Puzzles for that year
+    pub fn new(years: &[&'static [Puzzle]]) -> Self {
+        Registry {
+            years: years.iter().copied().filter(|y| !y.is_empty()).collect(),
+        }
+    }
+
+    /// Gets every year in this Registry
+    pub fn years(&self) -> impl Iterator<Item = u32> + '_ {
+        self.years.iter().map(|y| y[0].year)
+    }
+
+    /// Gets all of the Puzzles for @year, sorted by day
+    pub fn year(&self, year: u32) -> &[Puzzle] {
+        self.years
+            .iter()
+            .find(|y| y[0].year == year)
+            .copied()
+            .unwrap_or(&[])
+    }
+
+    /// Gets the Puzzle for @year @day, or None if it doesn't exist
+    pub fn get(&self, year: u32, day: usize) -> Option<&Puzzle> {
+        self.year(year).iter().find(|p| p.day == day)
+    }
+
+    /// Gets an iterator over every Puzzle in this Registry
+    pub fn iter(&self) -> impl Iterator<Item = &Puzzle> {
+        self.years.iter().flat_map(|y| y.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answer_json() {
+        let answers = [
+            Answer::from(12_u64),
+            Answer::from(-3_i32),
+            Answer::from("1,2,3"),
+            Answer::Unsolved,
+        ];
+
+        assert_eq!(
+            serde_json::to_string(&answers).unwrap(),
+            r#"[12,-3,"1,2,3",null]"#
+        );
+    }
+
+    struct Panics;
+    impl Solution for Panics {
+        const YEAR: u32 = 2000;
+        const DAY: usize = 1;
+        type Input<'a> = &'a str;
+        fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+            Ok(input)
+        }
+        fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+            Ok(input.parse::<u32>().unwrap().into())
+        }
+    }
+
+    #[test]
+    fn panics_have_locations() {
+        let result = Puzzle::new::<Panics>().run("seven").unwrap();
+
+        match &result.part1.error {
+            Some(AocError::Panic(msg)) => {
+                assert!(msg.contains("ParseIntError"), "{}", msg);
+                assert!(msg.contains("solution.rs:"), "{}", msg);
+            }
+            e => panic!("expected a panic, got {:?}", e),
+        }
+        assert!(result.part2.error.is_none());
+    }
+
+    #[test]
+    fn answer_roundtrip() {
+        for answer in [
+            Answer::Integer(i128::MAX),
+            Answer::Integer(i128::MIN),
+            Answer::from(-3_i32),
+            Answer::from(" #.\n.# "),
+            Answer::from("007"),
+            Answer::from("+5"),
+            Answer::Unsolved,
+        ] {
+            let json = serde_json::to_string(&answer).unwrap();
+            assert_eq!(serde_json::from_str::<Answer>(&json).unwrap(), answer);
+        }
+
+        assert_eq!("-42\n".parse::<Answer>().unwrap(), Answer::Integer(-42));
+        assert_eq!("007".parse::<Answer>().unwrap(), Answer::from("007"));
+        assert_eq!("+5".parse::<Answer>().unwrap(), Answer::from("+5"));
+    }
+}
@@ -0,0 +1,155 @@
+//! Progress on each day, worked out from the files in the project: solutions,
+//! inputs, recorded answers and the timing history
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::answers::{Verdict, YearAnswers};
+use crate::history::{Record, Source};
+use crate::input::Inputs;
+use crate::solution::{Answer, Puzzle};
+
+/// State of one part of a day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartStatus {
+    /// The last run gave the recorded answer
+    Verified,
+    /// The last run gave an answer other than the recorded one
+    Wrong,
+    /// There's a recorded answer, but the part hasn't been run since
+    Recorded,
+    /// The last run gave an answer, but none is recorded
+    Solved,
+    /// The last run returned Answer::Unsolved
+    Unsolved,
+    /// Nothing is known about the part
+    Unknown,
+}
+
+impl PartStatus {
+    /// Works out the status of a part from its @recorded answer and the answer
+    /// it gave the @last time it was run
+    pub fn new(recorded: Option<&Answer>, last: Option<&Answer>) -> Self {
+        match (recorded, last) {
+            (_, Some(a)) if !a.is_solved() => PartStatus::Unsolved,
+            (Some(r), Some(a)) => match Verdict::check(Some(r), a) {
+                Verdict::Pass => PartStatus::Verified,
+                _ => PartStatus::Wrong,
+            },
+            (Some(_), None) => PartStatus::Recorded,
+            (None, Some(_)) => PartStatus::Solved,
+            (None, None) => PartStatus::Unknown,
+        }
+    }
+
+    /// Gets the character the status is shown as
+    pub fn symbol(&self) -> char {
+        match self {
+            PartStatus::Verified => '*',
+            PartStatus::Wrong => '!',
+            PartStatus::Recorded => '+',
+            PartStatus::Solved => 'o',
+            PartStatus::Unsolved => '.',
+            PartStatus::Unknown => ' ',
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayStatus {
+    pub day: usize,
+    /// Whether there's a solution module for the day
+    pub solution: bool,
+    pub input: bool,
+    pub example: bool,
+    pub parts: [PartStatus; 2],
+    /// Total time of the last parse, part 1 and part 2 that were measured
+    pub runtime: Option<Duration>,
+}
+
+/// Gets the status of every day of @year, given its @puzzles, @answers and the
+/// timing @history of every year
+pub fn year(
+    year: u32,
+    puzzles: &[Puzzle],
+    inputs: &Inputs,
+    answers: &YearAnswers,
+    history: &[Record],
+) -> Vec<DayStatus> {
+    // the history is in the order it was recorded, so later Records win
+    let mut times = HashMap::new();
+    let mut last_answers = HashMap::new();
+
+    for r in history.iter().filter(|r| r.year == year) {
+        times.insert((r.day, r.step.as_str()), r.time_ns);
+
+        if let (Source::Run, Some(answer)) = (r.source, &r.answer) {
+            last_answers.insert((r.day, r.step.as_str()), answer);
+        }
+    }
+
+    (1..=25)
+        .map(|day| {
+            let puzzle = puzzles.iter().find(|p| p.day == day);
+            let dir = inputs.year_dir(year);
+            let example = match puzzle {
+                Some(p) if !p.examples.is_empty() => p
+                    .examples
+                    .iter()
+                    .any(|e| inputs.example_path(p, e).is_file()),
+                _ => dir.join(format!("day{}_example.txt", day)).is_file(),
+            };
+            let part = |part: usize| {
+                let step = if part == 1 { "part1" } else { "part2" };
+
+                PartStatus::new(
+                    answers.get(day, part),
+                    last_answers.get(&(day, step)).copied(),
+                )
+            };
+            let runtime = ["parse", "part1", "part2"]
+                .iter()
+                .map(|step| times.get(&(day, *step)).copied())
+                .sum::<Option<u64>>()
+                .map(Duration::from_nanos);
+
+            DayStatus {
+                day,
+                solution: puzzle.is_some(),
+                input: dir.join(format!("day{}.txt", day)).is_file(),
+                example,
+                parts: [part(1), part(2)],
+                runtime,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_statuses() {
+        let recorded = Answer::from(42);
+
+        assert_eq!(
+            PartStatus::new(Some(&recorded), Some(&Answer::from(42))),
+            PartStatus::Verified
+        );
+        assert_eq!(
+            PartStatus::new(Some(&recorded), Some(&Answer::from(41))),
+            PartStatus::Wrong
+        );
+        assert_eq!(
+            PartStatus::new(Some(&recorded), Some(&Answer::Unsolved)),
+            PartStatus::Unsolved
+        );
+        assert_eq!(PartStatus::new(Some(&recorded), None), PartStatus::Recorded);
+        assert_eq!(
+            PartStatus::new(None, Some(&Answer::from(42))),
+            PartStatus::Solved
+        );
+        assert_eq!(PartStatus::new(None, None), PartStatus::Unknown);
+    }
+}
@@ -0,0 +1,252 @@
+//! Watch mode: re-runs a day whenever its source, its year's shared modules,
+//! aocutils or its input changes
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use aocutils::input;
+use aocutils::solution::{Answer, Puzzle};
+use notify::{EventKind, RecursiveMode, Watcher};
+use serde::Deserialize;
+
+/// How long to wait for more changes before re-running, since saving a file
+/// usually produces several events
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// A file to watch for changes, or every file under a directory
+struct Target {
+    path: PathBuf,
+    recursive: bool,
+}
+
+impl Target {
+    /// Creates a Target for @path, which is made absolute so that it can be
+    /// compared with the paths of events
+    fn new(path: &Path, recursive: bool) -> io::Result<Self> {
+        let dir = path.parent().unwrap_or(Path::new("."));
+        let dir = fs::canonicalize(dir)?;
+
+        Ok(Target {
+            path: match path.file_name() {
+                Some(name) if !recursive => dir.join(name),
+                _ => fs::canonicalize(path)?,
+            },
+            recursive,
+        })
+    }
+
+    /// The directory to ask for notifications about. Files are watched through
+    /// their directory, since editors often save by replacing the file
+    fn watch_path(&self) -> &Path {
+        if self.recursive {
+            &self.path
+        } else {
+            self.path.parent().unwrap()
+        }
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        if self.recursive {
+            path.starts_with(&self.path)
+        } else {
+            path == self.path
+        }
+    }
+}
+
+/// Checks whether @path is one of the modules in @year_dir that days share,
+/// like src/2023/pipe.rs, rather than a day's solution
+fn is_shared_module(year_dir: &Path, path: &Path) -> bool {
+    let is_day = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .and_then(|s| s.strip_prefix("day"))
+        .is_some_and(|d| d.parse::<usize>().is_ok());
+
+    path.parent() == Some(year_dir) && path.extension().is_some_and(|e| e == "rs") && !is_day
+}
+
+#[derive(Deserialize)]
+struct Part {
+    answer: Answer,
+    time_ns: u64,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Output {
+    Ok { part1: Part, part2: Part },
+    Err { error: String },
+}
+
+/// Describes @current, compared against the answer from the previous run
+fn describe(previous: Option<&Answer>, current: &Answer) -> String {
+    match previous {
+        Some(p) if p == current => format!("{} (unchanged)", current),
+        Some(p) => format!("{} (was {})", current, p),
+        None => current.to_string(),
+    }
+}
+
+/// Builds and runs @puzzle on @input in a child process, so that changes to the
+/// code are picked up. Build errors are shown as they happen
+fn run_once(puzzle: &Puzzle, input: &Path, release: bool) -> io::Result<Output> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut cmd = Command::new(cargo);
+
+    cmd.current_dir(input::project_root())
+        .args(["run", "--quiet", "--bin", "aoc"]);
+    if release {
+        cmd.arg("--release");
+    }
+
+    let output = cmd
+        .args(["--", "run", "--format", "json", "--input"])
+        .arg(input)
+        .args([
+            "-y",
+            &puzzle.year.to_string(),
+            "-d",
+            &puzzle.day.to_string(),
+        ])
+        .stderr(Stdio::inherit())
+        .output()?;
+
+    if !output.status.success() {
+        return Err(io::Error::other("build failed"));
+    }
+
+    let mut outputs: Vec<Output> = serde_json::from_slice(&output.stdout)?;
+    outputs.pop().ok_or_else(|| io::Error::other("no results"))
+}
+
+/// Runs @puzzle on @input, then again every time the day's source file, a module
+/// shared by its year, aocutils, or @input changes. Each run's answers are
+/// compared with the previous run's
+pub fn watch(puzzle: &Puzzle, input: &Path, release: bool) -> io::Result<()> {
+    let root = input::project_root();
+    let src = root.join("src");
+    let targets = [
+        Target::new(
+            &src.join(puzzle.year.to_string())
+                .join(format!("day{}.rs", puzzle.day)),
+            false,
+        )?,
+        Target::new(&src.join("utils"), true)?,
+        Target::new(&src.join("lib.rs"), false)?,
+        Target::new(input, false)?,
+    ];
+    // the day's file is watched through the year directory, which has its shared
+    // modules too
+    let year_dir = targets[0].watch_path().to_owned();
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx).map_err(io::Error::other)?;
+
+    for target in &targets {
+        let mode = if target.recursive {
+            RecursiveMode::Recursive
+        } else {
+            RecursiveMode::NonRecursive
+        };
+
+        watcher
+            .watch(target.watch_path(), mode)
+            .map_err(io::Error::other)?;
+    }
+
+    println!(
+        "Watching {} day {} on {}, press Ctrl-C to stop",
+        puzzle.year,
+        puzzle.day,
+        input.display()
+    );
+
+    let mut previous: Option<[Answer; 2]> = None;
+
+    loop {
+        match run_once(puzzle, input, release) {
+            Ok(Output::Ok { part1, part2 }) => {
+                for (i, part) in [&part1, &part2].into_iter().enumerate() {
+                    let before = previous.as_ref().map(|p| &p[i]);
+
+                    if let Some(e) = &part.error {
+                        println!("part {}: Error! ({})", i + 1, e);
+                        continue;
+                    }
+
+                    println!(
+                        "part {}: {}  [{:.2?}]",
+                        i + 1,
+                        describe(before, &part.answer),
+                        Duration::from_nanos(part.time_ns)
+                    );
+                }
+
+                previous = Some([part1.answer, part2.answer]);
+            }
+            Ok(Output::Err { error }) => println!("Error! ({})", error),
+            Err(e) => println!("Error! ({})", e),
+        }
+
+        // wait for a relevant change, then for things to settle down
+        loop {
+            let event = rx
+                .recv()
+                .map_err(io::Error::other)?
+                .map_err(io::Error::other)?;
+            let relevant = matches!(
+                event.kind,
+                EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+            ) && event
+                .paths
+                .iter()
+                .any(|p| targets.iter().any(|t| t.matches(p)) || is_shared_module(&year_dir, p));
+
+            if relevant {
+                break;
+            }
+        }
+
+        while rx.recv_timeout(DEBOUNCE).is_ok() {}
+        println!();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answer_diffs() {
+        let old = Answer::from(10);
+
+        assert_eq!(describe(None, &Answer::from(10)), "10");
+        assert_eq!(describe(Some(&old), &Answer::from(10)), "10 (unchanged)");
+        assert_eq!(describe(Some(&old), &Answer::from(12)), "12 (was 10)");
+        assert_eq!(describe(Some(&old), &Answer::Unsolved), "unsolved (was 10)");
+    }
+
+    #[test]
+    fn targets() {
+        let dir = fs::canonicalize(env::temp_dir()).unwrap();
+        let file = Target::new(&dir.join("day1.txt"), false).unwrap();
+
+        assert_eq!(file.watch_path(), dir);
+        assert!(file.matches(&dir.join("day1.txt")));
+        assert!(!file.matches(&dir.join("day1_example.txt")));
+
+        let year = Path::new("/aoc/src/2023");
+        assert!(is_shared_module(year, &year.join("pipe.rs")));
+        assert!(!is_shared_module(year, &year.join("day10.rs")));
+        assert!(!is_shared_module(year, &year.join("notes.txt")));
+        assert!(!is_shared_module(year, Path::new("/aoc/src/2024/pipe.rs")));
+    }
+}